@@ -0,0 +1,101 @@
+use anyhow::{anyhow, Error, Result};
+use aoc::{Answer, Solution};
+use std::str::FromStr;
+
+struct Content {
+    groups: Vec<Vec<u32>>,
+}
+
+impl FromStr for Content {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let groups = aoc::parse::blocks(content)
+            .map(aoc::parse::numbers)
+            .collect::<Result<_>>()?;
+        Ok(Self { groups })
+    }
+}
+
+#[derive(Clone)]
+struct Inventory {
+    total_calories: u32,
+}
+
+impl Inventory {
+    fn new(inventory: Vec<u32>) -> Result<Self> {
+        let total_calories = inventory
+            .iter()
+            .try_fold(0u32, |acc, &calories| acc.checked_add(calories))
+            .ok_or_else(|| anyhow!("calories of inventory {:?} overflow", inventory))?;
+        Ok(Self { total_calories })
+    }
+    fn total_calories(&self) -> u32 {
+        self.total_calories
+    }
+}
+
+pub struct Inventories {
+    inventories: Vec<Inventory>,
+}
+
+impl Inventories {
+    fn new(inventories: Vec<Inventory>) -> Self {
+        Self { inventories }
+    }
+    fn from_content(content: Content) -> Result<Self> {
+        let inventories = content
+            .groups
+            .into_iter()
+            .map(Inventory::new)
+            .collect::<Result<_>>()?;
+        Ok(Self::new(inventories))
+    }
+    fn biggest_inventory(&self) -> Option<&Inventory> {
+        self.inventories
+            .iter()
+            .max_by_key(|inventory| inventory.total_calories())
+    }
+    fn n_biggest_inventories(&self, n: usize) -> Self {
+        let n_empty_inventories = vec![Inventory { total_calories: 0 }; n];
+        let mut found_inventories = n_empty_inventories.iter().collect::<Vec<_>>();
+        for current_inventory in self.inventories.iter() {
+            let smallest_found_inventory = found_inventories[0];
+            if current_inventory.total_calories() > smallest_found_inventory.total_calories() {
+                found_inventories[0] = current_inventory;
+                found_inventories.sort_by_key(|inventory| inventory.total_calories());
+            }
+        }
+        Inventories::new(found_inventories.into_iter().cloned().collect())
+    }
+    fn total_calories(&self) -> Result<u32> {
+        self.inventories
+            .iter()
+            .try_fold(0u32, |acc, inventory| {
+                acc.checked_add(inventory.total_calories())
+            })
+            .ok_or_else(|| anyhow!("total calories overflow"))
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Inventories;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Inventories::from_content(Content::from_str(input)?)
+    }
+    fn part1(&self, inventories: &Self::Input) -> Result<Answer> {
+        let biggest_inventory = inventories
+            .biggest_inventory()
+            .ok_or(anyhow!("there are no inventories"))?;
+        Ok(biggest_inventory.total_calories().into())
+    }
+    fn part2(&self, inventories: &Self::Input) -> Result<Answer> {
+        Ok(inventories
+            .n_biggest_inventories(3)
+            .total_calories()?
+            .into())
+    }
+}
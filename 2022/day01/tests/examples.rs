@@ -0,0 +1,14 @@
+use aoc::{testing::assert_parts, Solution};
+use aoc2022_day01::Day01;
+
+#[test]
+fn example() {
+    assert_parts(&Day01, include_str!("../input2.txt"), 24000, 45000);
+}
+
+#[test]
+fn calorie_overflow_is_an_error() {
+    assert!(Day01.parse("4294967295\n1").is_err());
+    let inventories = Day01.parse("4294967295\n\n4294967295").unwrap();
+    assert!(Day01.part2(&inventories).is_err());
+}
@@ -1,5 +1,6 @@
 use anyhow::{anyhow, Error, Result};
-use std::{fs::read_to_string, path::PathBuf, str::FromStr};
+use aoc::{parse, Answer, Solution};
+use std::str::FromStr;
 
 #[derive(PartialEq, Eq)]
 enum GameResult {
@@ -33,7 +34,7 @@ impl FromStr for GameResult {
             "X" => Self::Lose,
             "Y" => Self::Draw,
             "Z" => Self::Win,
-            _ => return Err(anyhow!("invalid game result string")),
+            _ => return Err(anyhow!("invalid game result {:?}", string)),
         })
     }
 }
@@ -46,9 +47,6 @@ enum Hand {
 }
 
 impl Hand {
-    fn all() -> Vec<Hand> {
-        vec![Self::Rock, Self::Paper, Self::Scissor]
-    }
     const fn wins(&self) -> Self {
         match self {
             Self::Rock => Self::Scissor,
@@ -59,11 +57,12 @@ impl Hand {
     fn draws(&self) -> Self {
         self.clone()
     }
-    fn loses(&self) -> Self {
-        Self::all()
-            .into_iter()
-            .find(|hand| &hand.wins() == self)
-            .unwrap()
+    const fn loses(&self) -> Self {
+        match self {
+            Self::Rock => Self::Paper,
+            Self::Paper => Self::Scissor,
+            Self::Scissor => Self::Rock,
+        }
     }
     fn create_from_result(&self, game_state: &GameResult) -> Self {
         match game_state {
@@ -89,7 +88,7 @@ impl FromStr for Hand {
             "A" | "X" => Self::Rock,
             "B" | "Y" => Self::Paper,
             "C" | "Z" => Self::Scissor,
-            _ => return Err(anyhow!("invalid hand string")),
+            _ => return Err(anyhow!("invalid hand {:?}", string)),
         })
     }
 }
@@ -105,7 +104,7 @@ impl FromStr for RockPaperScissors {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         let string_split = string.split_whitespace().collect::<Vec<_>>();
         let opponent_str = string_split
-            .get(0)
+            .first()
             .ok_or(anyhow!("cannot find your hand"))?;
         let you_str = string_split
             .get(1)
@@ -136,7 +135,7 @@ impl RockPaperScissors {
     fn from_str_2(str: &str) -> Result<Self> {
         let string_split = str.split_whitespace().collect::<Vec<_>>();
         let opponent_str = string_split
-            .get(0)
+            .first()
             .ok_or(anyhow!("cannot find your hand"))?;
         let game_result_str = string_split
             .get(1)
@@ -150,7 +149,7 @@ impl RockPaperScissors {
     }
 }
 
-struct RockPaperScissorsCollection {
+pub struct RockPaperScissorsCollection {
     collection: Vec<RockPaperScissors>,
 }
 
@@ -158,18 +157,14 @@ impl RockPaperScissorsCollection {
     fn new(collection: Vec<RockPaperScissors>) -> Self {
         Self { collection }
     }
-    fn try_from_string_vec_1(string_vec: Vec<&str>) -> Result<Self> {
-        let collection = string_vec
-            .into_iter()
-            .map(|str| RockPaperScissors::from_str(str))
-            .collect::<Result<Vec<_>>>()?;
+    fn try_from_content_1(content: &str) -> Result<Self> {
+        let collection =
+            parse::each_line(content, |line| RockPaperScissors::from_str(line.text()))?;
         Ok(Self::new(collection))
     }
-    fn try_from_string_vec_2(string_vec: Vec<&str>) -> Result<Self> {
-        let collection = string_vec
-            .into_iter()
-            .map(|str| RockPaperScissors::from_str_2(str))
-            .collect::<Result<Vec<_>>>()?;
+    fn try_from_content_2(content: &str) -> Result<Self> {
+        let collection =
+            parse::each_line(content, |line| RockPaperScissors::from_str_2(line.text()))?;
         Ok(Self::new(collection))
     }
     fn points(&self) -> u32 {
@@ -177,15 +172,21 @@ impl RockPaperScissorsCollection {
     }
 }
 
-fn read_file(filename: &str) -> Result<RockPaperScissorsCollection> {
-    let path = PathBuf::from(filename);
-    let content = read_to_string(path)?;
-    let string_vec = content.lines().collect();
-    RockPaperScissorsCollection::try_from_string_vec_2(string_vec)
-}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = (RockPaperScissorsCollection, RockPaperScissorsCollection);
 
-fn main() -> Result<()> {
-    let collection = read_file("input.txt")?;
-    dbg!(collection.points());
-    Ok(())
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok((
+            RockPaperScissorsCollection::try_from_content_1(input)?,
+            RockPaperScissorsCollection::try_from_content_2(input)?,
+        ))
+    }
+    fn part1(&self, (collection, _): &Self::Input) -> Result<Answer> {
+        Ok(collection.points().into())
+    }
+    fn part2(&self, (_, collection): &Self::Input) -> Result<Answer> {
+        Ok(collection.points().into())
+    }
 }
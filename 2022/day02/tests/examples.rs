@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2022_day02::Day02;
+
+#[test]
+fn example() {
+    assert_parts(&Day02, include_str!("../input2.txt"), 15, 12);
+}
@@ -1,6 +1,7 @@
 use std::collections::{hash_map::RandomState, HashMap, HashSet};
 
-use anyhow::Result;
+use anyhow::{anyhow, ensure, Result};
+use aoc::{parse, Answer, Solution};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 struct Item {
@@ -8,11 +9,16 @@ struct Item {
 }
 
 impl Item {
-    fn new(item_type: char) -> Self {
-        Self { item_type }
+    fn new(item_type: char) -> Result<Self> {
+        ensure!(
+            item_type.is_ascii_alphabetic(),
+            "invalid item {:?}",
+            item_type
+        );
+        Ok(Self { item_type })
     }
     fn points(&self) -> u32 {
-        let mut item_type_lowercase = self.item_type.clone();
+        let mut item_type_lowercase = self.item_type;
         item_type_lowercase.make_ascii_lowercase();
         item_type_lowercase as u32 - 'a' as u32 + self.item_type.is_uppercase() as u32 * 26 + 1
     }
@@ -27,11 +33,10 @@ impl Compartment {
     fn new(items: Vec<Item>) -> Self {
         Self { items }
     }
-    fn from_char(str: &str) -> Self {
-        Self::new(str.chars().into_iter().map(|chr| Item::new(chr)).collect())
-    }
-    fn contains_item(&self, item: &Item) -> bool {
-        self.items.contains(item)
+    fn from_char(str: &str) -> Result<Self> {
+        Ok(Self::new(
+            str.chars().map(Item::new).collect::<Result<_>>()?,
+        ))
     }
 }
 
@@ -44,9 +49,15 @@ impl Rucksack {
     fn new(compartments: Vec<Compartment>) -> Self {
         Self { compartments }
     }
-    fn from_item_str(rucksack_str: &str, compartment_count: usize) -> Self {
+    fn from_item_str(rucksack_str: &str, compartment_count: usize) -> Result<Self> {
+        ensure!(
+            !rucksack_str.is_empty() && rucksack_str.len().is_multiple_of(compartment_count),
+            "{} items cannot be split into {} compartments",
+            rucksack_str.len(),
+            compartment_count
+        );
         let mut compartment_strs = vec![];
-        let mut remaining_rucksack_str = rucksack_str.clone();
+        let mut remaining_rucksack_str = rucksack_str;
         let compartment_size = rucksack_str.len() / compartment_count;
         while remaining_rucksack_str.len() >= compartment_size * 2 {
             let cur_rucksack_str;
@@ -58,9 +69,9 @@ impl Rucksack {
 
         let compartments = compartment_strs
             .into_iter()
-            .map(|str| Compartment::from_char(str))
-            .collect();
-        Self::new(compartments)
+            .map(Compartment::from_char)
+            .collect::<Result<_>>()?;
+        Ok(Self::new(compartments))
     }
     fn common_items(&self) -> Vec<&Item> {
         let mut common_items: HashMap<&Item, u32> = HashMap::new();
@@ -86,7 +97,7 @@ impl Rucksack {
     }
 }
 
-struct RucksackCollection {
+pub struct RucksackCollection {
     rucksack_collection: Vec<Rucksack>,
 }
 
@@ -96,22 +107,20 @@ impl RucksackCollection {
             rucksack_collection,
         }
     }
-    fn from_rucksack_collection_strs(
-        rucksack_collection_strs: Vec<&str>,
-        compartments_per_rucksack: usize,
-    ) -> Self {
-        Self::new(
-            rucksack_collection_strs
-                .into_iter()
-                .map(|str| Rucksack::from_item_str(str, compartments_per_rucksack))
-                .collect(),
-        )
-    }
-    fn from_filename(filename: &str, compartments_per_rucksack: usize) -> Result<Self> {
-        Ok(Self::from_rucksack_collection_strs(
-            std::fs::read_to_string(filename)?.lines().collect(),
-            compartments_per_rucksack,
-        ))
+    fn from_content(content: &str, compartments_per_rucksack: usize) -> Result<Self> {
+        Ok(Self::new(parse::each_line(content, |line| {
+            let text = line.text();
+            if let Some((index, item_type)) = text
+                .char_indices()
+                .find(|(_, item_type)| !item_type.is_ascii_alphabetic())
+            {
+                let item_str = &text[index..index + item_type.len_utf8()];
+                return Err(line
+                    .error(item_str, format!("invalid item {:?}", item_type))
+                    .into());
+            }
+            Rucksack::from_item_str(text, compartments_per_rucksack)
+        })?))
     }
     fn common_item_points(&self) -> u32 {
         let common_items = self
@@ -121,17 +130,17 @@ impl RucksackCollection {
             .collect::<Vec<_>>();
         common_items.iter().fold(0, |acc, cur_vec| {
             acc + cur_vec
-                .into_iter()
+                .iter()
                 .fold(0, |acc_inner, cur_item| acc_inner + cur_item.points())
         })
     }
-    fn into_group_collection(&self, group_size: usize) -> GroupCollection {
+    fn to_group_collection(&self, group_size: usize) -> GroupCollection {
         let groups = self
             .rucksack_collection
             .iter()
             .as_slice()
             .chunks(group_size)
-            .map(|rucksacks| Group::from_rucksacks(rucksacks))
+            .map(Group::from_rucksacks)
             .collect::<Vec<_>>();
         GroupCollection::new(groups)
     }
@@ -151,7 +160,7 @@ impl Group {
         let rucksack_collection = RucksackCollection::new(rucksacks.to_vec());
         Self::new(rucksack_collection)
     }
-    fn common_rucksack_item(&self) -> Item {
+    fn common_rucksack_item(&self) -> Result<Item> {
         let item_collections = self
             .rucksack_collection
             .rucksack_collection
@@ -160,7 +169,13 @@ impl Group {
             .collect();
         let rucksack = Rucksack::new(item_collections);
         let common_items = rucksack.common_items();
-        common_items[0].clone()
+        match common_items.as_slice() {
+            [item] => Ok((*item).clone()),
+            _ => Err(anyhow!(
+                "group has {} common items, expected 1",
+                common_items.len()
+            )),
+        }
     }
 }
 
@@ -172,16 +187,26 @@ impl GroupCollection {
     fn new(groups: Vec<Group>) -> Self {
         Self { groups }
     }
-    fn common_item_points(&self) -> u32 {
+    fn common_item_points(&self) -> Result<u32> {
         self.groups
             .iter()
-            .fold(0, |acc, cur| acc + cur.common_rucksack_item().points())
+            .try_fold(0, |acc, cur| Ok(acc + cur.common_rucksack_item()?.points()))
     }
 }
 
-fn main() -> Result<()> {
-    let rucksack_collection = RucksackCollection::from_filename("input.txt", 2)?;
-    let group_collection = rucksack_collection.into_group_collection(3);
-    dbg!(group_collection.common_item_points());
-    Ok(())
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = RucksackCollection;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        RucksackCollection::from_content(input, 2)
+    }
+    fn part1(&self, rucksack_collection: &Self::Input) -> Result<Answer> {
+        Ok(rucksack_collection.common_item_points().into())
+    }
+    fn part2(&self, rucksack_collection: &Self::Input) -> Result<Answer> {
+        let group_collection = rucksack_collection.to_group_collection(3);
+        Ok(group_collection.common_item_points()?.into())
+    }
 }
@@ -0,0 +1,14 @@
+use aoc::{parse::ParseError, testing::assert_parts, Solution};
+use aoc2022_day03::Day03;
+
+#[test]
+fn example() {
+    assert_parts(&Day03, include_str!("../input2.txt"), 157, 70);
+}
+
+#[test]
+fn non_ascii_items_are_located() {
+    let error = Day03.parse("abcd\naé").err().unwrap();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (2, 2));
+}
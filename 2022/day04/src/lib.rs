@@ -0,0 +1,57 @@
+use anyhow::Result;
+use aoc::{
+    interval::IntervalSet,
+    parse::{self, Line},
+    Answer, Solution,
+};
+
+type Assignment = IntervalSet<u32>;
+
+fn ranges_contains_eachother(
+    ranges: &[Assignment],
+    func: fn(a: &Assignment, &Assignment) -> bool,
+) -> bool {
+    ranges.iter().enumerate().any(|(i, range)| {
+        ranges
+            .iter()
+            .enumerate()
+            .any(|(j, range2)| i != j && (func(range, range2)))
+    })
+}
+
+fn parse_ranges(line: Line) -> Result<Vec<Assignment>> {
+    line.text()
+        .split(',')
+        .map(|str| match line.numbers::<u32>(str)?.as_slice() {
+            [start, end] if start <= end => Ok(IntervalSet::from_range(*start..=*end)),
+            _ => Err(line.error(str, "invalid range").into()),
+        })
+        .collect()
+}
+
+fn count_ranges_containing_eachother(
+    ranges_vec: &[Vec<Assignment>],
+    func: fn(a: &Assignment, &Assignment) -> bool,
+) -> usize {
+    ranges_vec
+        .iter()
+        .filter(|ranges| ranges_contains_eachother(ranges, func))
+        .count()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Vec<Assignment>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse::each_line(input, parse_ranges)
+    }
+    fn part1(&self, ranges_vec: &Self::Input) -> Result<Answer> {
+        Ok(count_ranges_containing_eachother(ranges_vec, IntervalSet::is_superset).into())
+    }
+    fn part2(&self, ranges_vec: &Self::Input) -> Result<Answer> {
+        let overlaps = |a: &Assignment, b: &Assignment| !a.is_disjoint(b);
+        Ok(count_ranges_containing_eachother(ranges_vec, overlaps).into())
+    }
+}
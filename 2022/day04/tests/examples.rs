@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2022_day04::Day04;
+
+#[test]
+fn example() {
+    assert_parts(&Day04, include_str!("../input2.txt"), 2, 4);
+}
@@ -0,0 +1,201 @@
+use anyhow::{anyhow, ensure, Context, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+
+#[derive(Clone)]
+struct Crate {
+    crate_type: char,
+}
+
+impl Crate {
+    fn from_char(chr: char) -> Result<Self> {
+        match chr {
+            'A'..='Z' => Ok(Self { crate_type: chr }),
+            _ => Err(anyhow!("invalid char")),
+        }
+    }
+}
+
+#[derive(Clone)]
+struct CrateStack {
+    crates: Vec<Crate>,
+}
+
+impl CrateStack {
+    fn new(crates: Vec<Crate>) -> Self {
+        Self { crates }
+    }
+    fn top(&self) -> Option<&Crate> {
+        self.crates.last()
+    }
+}
+
+#[derive(Clone)]
+pub struct CrateStacks {
+    stacks: Vec<CrateStack>,
+}
+
+impl CrateStacks {
+    fn try_from_lines(lines: &[&str]) -> Result<Self> {
+        let width = lines
+            .iter()
+            .map(|line| line.len())
+            .max()
+            .ok_or(anyhow!("no lines"))?;
+        let stack_count = width.div_ceil(4);
+        let mut stacks = vec![vec![]; stack_count];
+        for line in lines.iter().map(|line| line.chars()) {
+            for (i, char) in line.skip(1).step_by(4).enumerate() {
+                if char.is_ascii_uppercase() {
+                    stacks[i].push(Crate::from_char(char)?);
+                }
+            }
+        }
+        let crate_stacks = stacks
+            .into_iter()
+            .map(|stack| CrateStack::new(stack.into_iter().rev().collect()))
+            .collect();
+
+        Ok(Self {
+            stacks: crate_stacks,
+        })
+    }
+    fn stack_index(&self, stack: u32) -> Result<usize> {
+        let index = (stack as usize)
+            .checked_sub(1)
+            .filter(|&index| index < self.stacks.len())
+            .ok_or_else(|| {
+                anyhow!(
+                    "stack {} does not exist, there are {} stacks",
+                    stack,
+                    self.stacks.len()
+                )
+            })?;
+        Ok(index)
+    }
+    fn take_crates(&mut self, procedure: &Procedure) -> Result<Vec<Crate>> {
+        let from_index = self.stack_index(procedure.from)?;
+        let from_crates = &mut self.stacks[from_index].crates;
+        let count = procedure.count as usize;
+        ensure!(
+            count <= from_crates.len(),
+            "cannot move {} crates from stack {} holding {}",
+            count,
+            procedure.from,
+            from_crates.len()
+        );
+        Ok(from_crates.split_off(from_crates.len() - count))
+    }
+    fn apply_procedure_9000(&mut self, procedure: &Procedure) -> Result<()> {
+        let to_index = self.stack_index(procedure.to)?;
+        let crates = self.take_crates(procedure)?;
+        self.stacks[to_index]
+            .crates
+            .extend(crates.into_iter().rev());
+        Ok(())
+    }
+    fn apply_procedure_9001(&mut self, procedure: &Procedure) -> Result<()> {
+        let to_index = self.stack_index(procedure.to)?;
+        let crates = self.take_crates(procedure)?;
+        self.stacks[to_index].crates.extend(crates);
+        Ok(())
+    }
+    fn apply_procedures(
+        &mut self,
+        procedures: &Procedures,
+        apply_procedure_func: fn(&mut Self, &Procedure) -> Result<()>,
+    ) -> Result<()> {
+        for (index, procedure) in procedures.procedures.iter().enumerate() {
+            apply_procedure_func(self, procedure)
+                .with_context(|| format!("procedure {} failed", index + 1))?;
+        }
+        Ok(())
+    }
+    fn top(&self) -> Vec<&Crate> {
+        self.stacks.iter().filter_map(|stack| stack.top()).collect()
+    }
+    fn top_str(&self) -> String {
+        self.top().iter().fold(String::new(), |acc, cur_crate| {
+            acc + cur_crate.crate_type.to_string().as_str()
+        })
+    }
+}
+
+#[derive(Clone)]
+struct Procedure {
+    count: u32,
+    from: u32,
+    to: u32,
+}
+
+impl Procedure {
+    fn from_line(line: Line) -> Result<Self> {
+        let [count, from, to] = line.scan("move {} from {} to {}")?;
+        Ok(Self {
+            count: line.parse(count)?,
+            from: line.parse(from)?,
+            to: line.parse(to)?,
+        })
+    }
+}
+
+pub struct Procedures {
+    procedures: Vec<Procedure>,
+}
+
+impl Procedures {
+    fn try_from_lines(lines: &[Line]) -> Result<Self> {
+        let procedures = lines
+            .iter()
+            .map(|&line| Procedure::from_line(line))
+            .collect::<Result<_>>()?;
+        Ok(Self { procedures })
+    }
+}
+
+fn parse_content(content: &str) -> Result<(CrateStacks, Procedures)> {
+    let (stacks_str, procedures_str) = parse::lines(content).fold(
+        (vec![], vec![]),
+        |(mut acc_stacks, mut acc_procedures), cur_line| {
+            if cur_line.text().is_empty() {
+            } else if cur_line.text().starts_with("move") {
+                acc_procedures.push(cur_line);
+            } else {
+                acc_stacks.push(cur_line.text());
+            }
+            (acc_stacks, acc_procedures)
+        },
+    );
+
+    let crate_stacks = CrateStacks::try_from_lines(stacks_str.as_slice())?;
+    let procedures = Procedures::try_from_lines(procedures_str.as_slice())?;
+
+    Ok((crate_stacks, procedures))
+}
+
+fn top_str_after_procedures(
+    (crate_stacks, procedures): &(CrateStacks, Procedures),
+    apply_procedure_func: fn(&mut CrateStacks, &Procedure) -> Result<()>,
+) -> Result<String> {
+    let mut crate_stacks = crate_stacks.clone();
+    crate_stacks.apply_procedures(procedures, apply_procedure_func)?;
+    Ok(crate_stacks.top_str())
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (CrateStacks, Procedures);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        parse_content(input)
+    }
+    fn part1(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(top_str_after_procedures(input, CrateStacks::apply_procedure_9000)?.into())
+    }
+    fn part2(&self, input: &Self::Input) -> Result<Answer> {
+        Ok(top_str_after_procedures(input, CrateStacks::apply_procedure_9001)?.into())
+    }
+}
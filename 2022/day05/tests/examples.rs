@@ -0,0 +1,15 @@
+use aoc::{parse::ParseError, testing::assert_parts, Solution};
+use aoc2022_day05::Day05;
+
+#[test]
+fn example() {
+    assert_parts(&Day05, include_str!("../input2.txt"), "CMZ", "MCD");
+}
+
+#[test]
+fn malformed_procedure_is_located() {
+    let input = include_str!("../input2.txt").replace("move 3 from 1 to 3", "move 3 from 1 too 3");
+    let error = Day05.parse(&input).err().unwrap();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.column()), (7, 13));
+}
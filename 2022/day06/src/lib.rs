@@ -0,0 +1,49 @@
+use anyhow::{anyhow, Result};
+use aoc::{Answer, Solution};
+use std::collections::{hash_map::RandomState, HashSet};
+
+pub struct Signal {
+    signal: String,
+}
+
+impl Signal {
+    fn new(signal: String) -> Self {
+        Self { signal }
+    }
+    fn find_first_maker_index(&self, chunk_size: usize) -> Option<usize> {
+        self.signal
+            .as_bytes()
+            .windows(chunk_size)
+            .position(|chunk| HashSet::<_, RandomState>::from_iter(chunk).len() == chunk_size)
+            .map(|start_i| start_i + chunk_size)
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Signal;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let signal = input.trim_end();
+        if let Some(character) = signal.chars().find(|character| !character.is_ascii()) {
+            return Err(anyhow!(
+                "signal contains non-ASCII character {:?}",
+                character
+            ));
+        }
+        Ok(Signal::new(signal.to_string()))
+    }
+    fn part1(&self, signal: &Self::Input) -> Result<Answer> {
+        let index = signal
+            .find_first_maker_index(4)
+            .ok_or(anyhow!("cannot find start-of-packet marker"))?;
+        Ok(index.into())
+    }
+    fn part2(&self, signal: &Self::Input) -> Result<Answer> {
+        let index = signal
+            .find_first_maker_index(14)
+            .ok_or(anyhow!("cannot find start-of-message marker"))?;
+        Ok(index.into())
+    }
+}
@@ -0,0 +1,12 @@
+use aoc::{testing::assert_parts, Solution};
+use aoc2022_day06::Day06;
+
+#[test]
+fn example() {
+    assert_parts(&Day06, include_str!("../input2.txt"), 10, 29);
+}
+
+#[test]
+fn non_ascii_signal_is_rejected() {
+    assert!(Day06.parse("abcé").is_err());
+}
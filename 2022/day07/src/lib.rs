@@ -0,0 +1,179 @@
+use std::iter::Peekable;
+
+use anyhow::{anyhow, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+
+type Lines<'a> = Peekable<Box<dyn Iterator<Item = Line<'a>> + 'a>>;
+
+#[derive(Debug)]
+enum CdSpecial {
+    Root,
+    Back,
+}
+
+#[derive(Debug)]
+struct File {
+    size: usize,
+}
+
+impl File {
+    fn new(size: usize) -> Self {
+        Self { size }
+    }
+}
+
+#[derive(Debug)]
+pub struct Directory {
+    name: String,
+    directories: Vec<Directory>,
+    files: Vec<File>,
+}
+
+impl Directory {
+    fn new_empty(name: String) -> Self {
+        Self {
+            name,
+            directories: vec![],
+            files: vec![],
+        }
+    }
+    fn new_root() -> Self {
+        Self::new_empty("root".to_string())
+    }
+    fn cd(
+        &mut self,
+        line: Line,
+        cd_dir_name: &str,
+        lines: &mut Lines,
+    ) -> Result<Option<CdSpecial>> {
+        match cd_dir_name {
+            ".." => Ok(Some(CdSpecial::Back)),
+            "/" => Ok(Some(CdSpecial::Root)),
+            _ => {
+                let child_dir = self
+                    .directories
+                    .iter_mut()
+                    .find(|dir| dir.name == cd_dir_name)
+                    .ok_or_else(|| line.error(cd_dir_name, "cannot find child dir with name"))?;
+                child_dir.read_lines(lines)
+            }
+        }
+    }
+    fn ls(&mut self, lines: &mut Lines) -> Result<()> {
+        while let Some(&line) = lines.peek() {
+            match line
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                ["dir", dir_name] => self.directories.push(Self::new_empty(dir_name.to_string())),
+                ["$", ..] => return Ok(()),
+                [size, _] => self.files.push(File::new(line.parse(size)?)),
+                _ => return Err(line.error(line.text(), "invalid listing").into()),
+            }
+            lines.next();
+        }
+        Ok(())
+    }
+    fn read_lines(&mut self, lines: &mut Lines) -> Result<Option<CdSpecial>> {
+        while let Some(line) = lines.next() {
+            match line
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                ["$", "cd", cd_dir_name] => match self.cd(line, cd_dir_name, lines)? {
+                    Some(CdSpecial::Back) => return Ok(None),
+                    Some(CdSpecial::Root) => return Ok(Some(CdSpecial::Root)),
+                    _ => (),
+                },
+                ["$", "ls"] => self.ls(lines)?,
+                _ => return Err(line.error(line.text(), "invalid command").into()),
+            }
+        }
+        Ok(None)
+    }
+    fn from_commands(content: &str) -> Result<Self> {
+        let lines: Box<dyn Iterator<Item = Line>> = Box::new(parse::lines(content));
+        let lines = &mut lines.peekable();
+
+        let mut root = Self::new_root();
+        while root.read_lines(lines)?.is_some() {}
+
+        Ok(root)
+    }
+    fn flatten_dirs(&self) -> Vec<&Directory> {
+        let children = self
+            .directories
+            .iter()
+            .flat_map(|dir| dir.flatten_dirs())
+            .collect::<Vec<_>>();
+
+        [vec![self], children].concat()
+    }
+    fn size(&self) -> Result<usize> {
+        let file_sizes = self.files.iter().map(|file| Ok(file.size));
+        let dir_sizes = self.directories.iter().map(|dir| dir.size());
+        file_sizes.chain(dir_sizes).try_fold(0usize, |acc, size| {
+            acc.checked_add(size?)
+                .ok_or_else(|| anyhow!("size of directory {} overflows", self.name))
+        })
+    }
+    fn dir_sizes(&self) -> Result<Vec<usize>> {
+        self.flatten_dirs()
+            .into_iter()
+            .map(|dir| dir.size())
+            .collect()
+    }
+    fn size_with_max(&self, max: usize) -> Result<usize> {
+        Ok(self
+            .dir_sizes()?
+            .into_iter()
+            .filter(|&size| size <= max)
+            .sum::<usize>())
+    }
+    fn reduce_size_by_deleting_this_directory(
+        &self,
+        file_system_size: usize,
+        needed_space: usize,
+    ) -> Result<Option<usize>> {
+        let size = self.size()?;
+        let free_space = file_system_size.checked_sub(size).ok_or_else(|| {
+            anyhow!(
+                "files take {} bytes, more than the file system size of {}",
+                size,
+                file_system_size
+            )
+        })?;
+        let space_to_remove = needed_space.saturating_sub(free_space);
+        Ok(self
+            .dir_sizes()?
+            .into_iter()
+            .filter(|&size| size >= space_to_remove)
+            .min())
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Directory;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Directory::from_commands(input)
+    }
+    fn part1(&self, directory: &Self::Input) -> Result<Answer> {
+        Ok(directory.size_with_max(100_000)?.into())
+    }
+    fn part2(&self, directory: &Self::Input) -> Result<Answer> {
+        let big_dir_size = directory
+            .reduce_size_by_deleting_this_directory(70_000_000, 30_000_000)?
+            .ok_or(anyhow!("cannot find directory that frees enough space"))?;
+        Ok(big_dir_size.into())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2022_day07::Day07;
+
+#[test]
+fn example() {
+    assert_parts(&Day07, include_str!("../input2.txt"), 95437, 24933642);
+}
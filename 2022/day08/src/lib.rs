@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Error, Result};
+use aoc::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    Answer, Solution,
+};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(PartialEq, Eq, Hash)]
+struct Tree {
+    position: (usize, usize),
+    height: u8,
+}
+
+impl Tree {
+    fn new(position: (usize, usize), height: u8) -> Self {
+        Self { position, height }
+    }
+}
+
+pub struct Forest {
+    trees: Grid<Tree>,
+}
+
+impl Forest {
+    fn new(trees: Grid<Tree>) -> Self {
+        Self { trees }
+    }
+    fn visible_trees_in_direction_at_index(
+        &self,
+        direction: Direction,
+        index: usize,
+    ) -> Vec<&Tree> {
+        let mut biggest_found_height = -1;
+        let tree_closure = |tree: &&Tree| {
+            let tree_height_i8 = tree.height as i8;
+            let is_higher = tree_height_i8 > biggest_found_height;
+            if is_higher {
+                biggest_found_height = tree_height_i8;
+            }
+            is_higher
+        };
+        match direction {
+            Direction::Up => self
+                .trees
+                .column(index)
+                .rev()
+                .filter(tree_closure)
+                .collect(),
+            Direction::Down => self.trees.column(index).filter(tree_closure).collect(),
+            Direction::Left => self.trees.row(index).rev().filter(tree_closure).collect(),
+            Direction::Right => self.trees.row(index).filter(tree_closure).collect(),
+        }
+    }
+    fn visible_trees_in_direction(&self, direction: Direction) -> HashSet<&Tree> {
+        let direction_len = if direction.is_horizontal() {
+            self.trees.height()
+        } else {
+            self.trees.width()
+        };
+        let mut visible_trees = HashSet::new();
+        (0..direction_len).for_each(|i| {
+            let visible_trees_in_direction_at_index =
+                self.visible_trees_in_direction_at_index(direction, i);
+            visible_trees.extend(visible_trees_in_direction_at_index);
+        });
+        visible_trees
+    }
+    fn visible_trees(&self) -> HashSet<&Tree> {
+        let mut visible_trees = HashSet::new();
+        for direction in Direction::ALL {
+            let visible_trees_in_direction = self.visible_trees_in_direction(direction);
+            visible_trees.extend(visible_trees_in_direction);
+        }
+        visible_trees
+    }
+    fn scenic_score_of_tree_at_position(&self, position: (usize, usize)) -> Option<u32> {
+        let tree_at_pos = self.trees.get(position)?;
+
+        let scenic_score = Direction::ALL
+            .into_iter()
+            .map(|direction| {
+                let mut cur_pos = Point::from(position).step(direction);
+                let mut viewing_distance = 0;
+
+                while let Some(cur_tree) = self.trees.get_signed(cur_pos) {
+                    viewing_distance += 1;
+                    if cur_tree.height >= tree_at_pos.height {
+                        break;
+                    }
+                    cur_pos = cur_pos.step(direction);
+                }
+
+                viewing_distance
+            })
+            .product::<u32>();
+
+        Some(scenic_score)
+    }
+    fn highest_scenic_score(&self) -> u32 {
+        self.trees
+            .positions()
+            .filter_map(|position| self.scenic_score_of_tree_at_position(position))
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+impl FromStr for Forest {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let trees = Grid::parse(string, |position, chr| {
+            let tree_height = chr
+                .to_digit(10)
+                .ok_or(anyhow!("tree height must be a digit"))?;
+            Ok(Tree::new(position, tree_height as u8))
+        })?;
+        Ok(Self::new(trees))
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Forest;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Forest::from_str(input)
+    }
+    fn part1(&self, forest: &Self::Input) -> Result<Answer> {
+        Ok(forest.visible_trees().len().into())
+    }
+    fn part2(&self, forest: &Self::Input) -> Result<Answer> {
+        Ok(forest.highest_scenic_score().into())
+    }
+}
@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2022_day08::Day08;
+
+#[test]
+fn example() {
+    assert_parts(&Day08, include_str!("../input2.txt"), 21, 8);
+}
@@ -0,0 +1,130 @@
+use anyhow::{Error, Result};
+use aoc::{
+    geometry::{Direction, Point},
+    parse, Answer, Solution,
+};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Clone)]
+struct Knot {
+    coordinate: Point,
+    visited_coordinates: HashSet<Point>,
+}
+
+impl Knot {
+    fn new(coordinate: Point) -> Self {
+        let visited_coordinates = HashSet::from([coordinate]);
+        Self {
+            coordinate,
+            visited_coordinates,
+        }
+    }
+    fn new_at_origo() -> Self {
+        Self::new(Point::ORIGIN)
+    }
+    fn update_coordinate(&mut self, coordinate: Point) {
+        self.visited_coordinates.insert(coordinate);
+        self.coordinate = coordinate;
+    }
+    fn move_in_direction(&mut self, direction: Direction) {
+        self.update_coordinate(self.coordinate.step(direction));
+    }
+    fn move_towards_coordinate(&mut self, coordinate: Point) {
+        if self.coordinate.chebyshev(coordinate) <= 1 {
+            return;
+        }
+        let step = (coordinate - self.coordinate).signum();
+        self.update_coordinate(self.coordinate + step)
+    }
+}
+
+struct Rope {
+    knots: Vec<Knot>,
+}
+
+impl Rope {
+    fn new_at_origo(knot_count: usize) -> Self {
+        assert!(knot_count >= 1, "cannot create rope with size less than 1");
+        let knots = vec![Knot::new_at_origo(); knot_count];
+        Self { knots }
+    }
+    fn head(&mut self) -> &mut Knot {
+        &mut self.knots[0]
+    }
+    fn tail(&mut self) -> &mut Knot {
+        let knot_count = self.knots.len();
+        &mut self.knots[knot_count - 1]
+    }
+    fn perform_motion(&mut self, head_knot_motion: &HeadKnotMotion) {
+        for _ in 0..head_knot_motion.movement_count {
+            self.head().move_in_direction(head_knot_motion.direction);
+            for i in 1..self.knots.len() {
+                let parent_knot_coordinate = self.knots[i - 1].coordinate;
+                self.knots[i].move_towards_coordinate(parent_knot_coordinate);
+            }
+        }
+    }
+    fn perform_motions(&mut self, head_knot_motions: &HeadKnotMotions) {
+        for motion in head_knot_motions.motions.iter() {
+            self.perform_motion(motion);
+        }
+    }
+}
+
+struct HeadKnotMotion {
+    direction: Direction,
+    movement_count: u32,
+}
+
+impl HeadKnotMotion {
+    fn new(direction: Direction, movement_count: u32) -> Self {
+        Self {
+            direction,
+            movement_count,
+        }
+    }
+}
+
+pub struct HeadKnotMotions {
+    motions: Vec<HeadKnotMotion>,
+}
+
+impl FromStr for HeadKnotMotions {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let motions = parse::each_line(content, |line| {
+            let [direction_str, movement_count_str] = line.scan("{} {}")?;
+            let direction = direction_str
+                .parse::<char>()
+                .map_err(Error::from)
+                .and_then(Direction::try_from)
+                .map_err(|error| line.error(direction_str, error))?;
+            let movement_count = line.parse(movement_count_str)?;
+            Ok(HeadKnotMotion::new(direction, movement_count))
+        })?;
+        Ok(Self { motions })
+    }
+}
+
+fn tail_visited_count(head_knot_motions: &HeadKnotMotions, knot_count: usize) -> usize {
+    let mut rope = Rope::new_at_origo(knot_count);
+    rope.perform_motions(head_knot_motions);
+    rope.tail().visited_coordinates.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = HeadKnotMotions;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        HeadKnotMotions::from_str(input)
+    }
+    fn part1(&self, head_knot_motions: &Self::Input) -> Result<Answer> {
+        Ok(tail_visited_count(head_knot_motions, 2).into())
+    }
+    fn part2(&self, head_knot_motions: &Self::Input) -> Result<Answer> {
+        Ok(tail_visited_count(head_knot_motions, 10).into())
+    }
+}
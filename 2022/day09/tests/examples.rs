@@ -0,0 +1,25 @@
+use aoc::{
+    parse::ParseError,
+    testing::{assert_part, assert_parts},
+    Part, Solution,
+};
+use aoc2022_day09::Day09;
+
+#[test]
+fn example() {
+    assert_parts(&Day09, include_str!("../input2.txt"), 13, 1);
+}
+
+#[test]
+fn larger_example() {
+    assert_part(&Day09, include_str!("../input3.txt"), Part::Two, 36);
+}
+
+#[test]
+fn malformed_motions_are_located() {
+    for (input, column) in [("garbage", 1), ("R 4\nX 2", 1), ("R 4\nU -2", 3)] {
+        let error = Day09.parse(input).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!(error.column(), column, "{}", input);
+    }
+}
@@ -1,9 +1,11 @@
-use anyhow::{anyhow, Result};
-use std::{
-    fmt::{Display, Write},
-    ops::AddAssign,
-    vec,
+use anyhow::{Context, Error, Result};
+use aoc::{
+    grid::Grid,
+    ocr,
+    parse::{self, Line},
+    Answer, Solution,
 };
+use std::{fmt::Display, ops::AddAssign, str::FromStr, vec};
 
 trait Instruction {
     fn cycles(&self) -> usize;
@@ -36,7 +38,7 @@ impl Instruction for Noop {
         1
     }
 
-    fn perform(&self, register: &mut isize) {}
+    fn perform(&self, _register: &mut isize) {}
 }
 
 struct InstructionStack {
@@ -51,32 +53,42 @@ impl InstructionStack {
         self.collection.reverse();
         self
     }
-    fn create_instruction_from_string(string: &str) -> Result<Box<dyn Instruction>> {
+    fn create_instruction_from_line(line: Line) -> Result<Box<dyn Instruction>> {
         Ok(
-            match string.split_whitespace().collect::<Vec<_>>().as_slice() {
-                ["addx", value_str] => Box::new(Addx::new(value_str.parse()?)),
+            match line
+                .text()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .as_slice()
+            {
+                ["addx", value_str] => Box::new(Addx::new(line.parse(value_str)?)),
                 ["noop"] => Box::new(Noop),
-                _ => return Err(anyhow!("instruction does not exist")),
+                _ => {
+                    return Err(line
+                        .error(line.text(), "expected \"addx <value>\" or \"noop\"")
+                        .into())
+                }
             },
         )
     }
-    fn try_from_filename(filename: &str) -> Result<Self> {
-        let content = std::fs::read_to_string(filename)?;
-        let vector = content
-            .lines()
-            .map(|line| Self::create_instruction_from_string(line))
-            .collect::<Result<Vec<_>>>()?;
+}
+
+impl FromStr for InstructionStack {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let vector = parse::each_line(content, Self::create_instruction_from_line)?;
         Ok(Self::new(vector).inverted())
     }
 }
 
-struct CPU {
+#[allow(clippy::upper_case_acronyms)]
+pub struct CPU {
     register: isize,
     ticks: usize,
     instruction_stack: InstructionStack,
     current_instruction: Option<Box<dyn Instruction>>,
     ticks_since_current_instruction_start: usize,
-    task1_out: isize,
     register_history: Vec<isize>,
 }
 
@@ -86,7 +98,6 @@ impl CPU {
         let ticks = 0;
         let current_instruction = None;
         let ticks_since_current_instruction_start = 0;
-        let task1_out = 0;
         let register_history = vec![register];
         Self {
             register,
@@ -94,7 +105,6 @@ impl CPU {
             instruction_stack: instruction_set,
             current_instruction,
             ticks_since_current_instruction_start,
-            task1_out,
             register_history,
         }
     }
@@ -117,7 +127,7 @@ impl CPU {
         self.ticks_since_current_instruction_start.add_assign(1);
         self.ticks.add_assign(1);
 
-        self.register_history.push(self.register.clone());
+        self.register_history.push(self.register);
 
         match instruction
             .cycles()
@@ -153,57 +163,64 @@ impl CPU {
 }
 
 struct Screen {
-    pixels: Vec<Vec<bool>>,
+    pixels: Grid<bool>,
 }
 
 impl Screen {
-    fn new(pixels: Vec<Vec<bool>>) -> Self {
+    fn new(pixels: Grid<bool>) -> Self {
         Self { pixels }
     }
-    fn from_registers(registers: Vec<isize>, x_width: usize) -> Self {
+    fn from_registers(registers: Vec<isize>, x_width: usize) -> Result<Self> {
         let mut pixels = vec![];
         let mut cur_row = vec![false; x_width];
 
         for (tick, register) in registers.iter().skip(1).enumerate() {
             let horizontal_pos = tick % x_width;
-            // let horizontal_pos = tick - x_width * vertical_pos;
             let is_lit = {
-                let diff = register - &(horizontal_pos as isize);
-                -1 <= diff && diff <= 1
+                let diff = register - horizontal_pos as isize;
+                (-1..=1).contains(&diff)
             };
-            if is_lit {
-                cur_row[horizontal_pos] = true;
-            }
             if tick != 0 && horizontal_pos == 0 {
                 pixels.push(cur_row);
                 cur_row = vec![false; x_width];
             }
+            if is_lit {
+                cur_row[horizontal_pos] = true;
+            }
         }
         pixels.push(cur_row);
 
-        Self::new(pixels)
+        Ok(Self::new(Grid::from_rows(pixels)?))
+    }
+    fn read(&self) -> Result<String> {
+        ocr::read_letters(&self.pixels).with_context(|| format!("cannot read screen\n{}", self))
     }
 }
 
 impl Display for Screen {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for (i, row) in self.pixels.iter().enumerate() {
-            if i != 0 {
-                f.write_char('\n')?;
-            }
-            let row_str = String::from_iter(row.iter().map(|pixel| if *pixel { "#" } else { "." }));
-            f.write_str(&row_str)?;
-        }
-        Ok(())
+        self.pixels
+            .map(|&pixel| if pixel { '#' } else { '.' })
+            .fmt(f)
     }
 }
 
-fn main() -> Result<()> {
-    let instruction_set = InstructionStack::try_from_filename("input.txt")?;
-    let mut cpu = CPU::new(instruction_set);
-    cpu.run_instructions();
-    dbg!(cpu.task1());
-    let screen = Screen::from_registers(cpu.register_history, 40);
-    println!("{}", screen);
-    Ok(())
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = CPU;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        let instruction_set = InstructionStack::from_str(input)?;
+        let mut cpu = CPU::new(instruction_set);
+        cpu.run_instructions();
+        Ok(cpu)
+    }
+    fn part1(&self, cpu: &Self::Input) -> Result<Answer> {
+        Ok(cpu.task1().into())
+    }
+    fn part2(&self, cpu: &Self::Input) -> Result<Answer> {
+        let screen = Screen::from_registers(cpu.register_history.clone(), 40)?;
+        Ok(screen.read()?.into())
+    }
 }
@@ -0,0 +1,22 @@
+use aoc::{parse::ParseError, testing::assert_part, Part, Solution};
+use aoc2022_day10::Day10;
+
+#[test]
+fn larger_example() {
+    assert_part(&Day10, include_str!("../input3.txt"), Part::One, 13140);
+}
+
+#[test]
+fn larger_example_image_is_not_text() {
+    let cpu = Day10.parse(include_str!("../input3.txt")).unwrap();
+    let error = Day10.part2(&cpu).unwrap_err();
+    assert!(format!("{:#}", error).contains("unrecognized glyphs at 0 (x = 0)"));
+    assert!(format!("{:#}", error).contains("##..##..##..##..##..##..##..##..##..##.."));
+}
+
+#[test]
+fn unknown_instruction_is_located() {
+    let error = Day10.parse("noop\naddx 3\njump 2").err().unwrap();
+    let error = error.downcast_ref::<ParseError>().unwrap();
+    assert_eq!((error.line(), error.snippet()), (3, "jump 2"));
+}
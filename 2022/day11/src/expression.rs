@@ -0,0 +1,231 @@
+use anyhow::{anyhow, Result};
+use aoc::parse::Line;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+}
+
+impl Operator {
+    fn from_symbol(symbol: &str) -> Option<Self> {
+        Some(match symbol {
+            "+" => Operator::Add,
+            "-" => Operator::Subtract,
+            "*" => Operator::Multiply,
+            "/" => Operator::Divide,
+            "%" => Operator::Remainder,
+            _ => return None,
+        })
+    }
+    fn symbol(self) -> char {
+        match self {
+            Operator::Add => '+',
+            Operator::Subtract => '-',
+            Operator::Multiply => '*',
+            Operator::Divide => '/',
+            Operator::Remainder => '%',
+        }
+    }
+    fn precedence(self) -> u8 {
+        match self {
+            Operator::Add | Operator::Subtract => 1,
+            Operator::Multiply | Operator::Divide | Operator::Remainder => 2,
+        }
+    }
+    fn apply(self, a: u64, b: u64) -> Result<u64> {
+        let (result, problem) = match self {
+            Operator::Add => (a.checked_add(b), "overflows"),
+            Operator::Subtract => (a.checked_sub(b), "goes below zero"),
+            Operator::Multiply => (a.checked_mul(b), "overflows"),
+            Operator::Divide => (a.checked_div(b), "divides by zero"),
+            Operator::Remainder => (a.checked_rem(b), "divides by zero"),
+        };
+        result.ok_or_else(|| anyhow!("worry level {} {} {} {}", a, self.symbol(), b, problem))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expression {
+    Old,
+    Number(u64),
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+impl Expression {
+    pub fn parse<'a>(line: &Line<'a>, text: &'a str) -> Result<Self> {
+        let tokens = tokenize(line, text)?;
+        let mut parser = Parser {
+            line,
+            tokens: &tokens,
+            end: &text[text.len()..],
+        };
+        let expression = parser.expression(1)?;
+        match parser.tokens.first() {
+            Some(token) => Err(line.error(token, "unexpected token").into()),
+            None => Ok(expression),
+        }
+    }
+    pub fn evaluate(&self, old: u64) -> Result<u64> {
+        match self {
+            Expression::Old => Ok(old),
+            Expression::Number(number) => Ok(*number),
+            Expression::Binary(a, operator, b) => {
+                operator.apply(a.evaluate(old)?, b.evaluate(old)?)
+            }
+        }
+    }
+    fn uses_old(&self) -> bool {
+        match self {
+            Expression::Old => true,
+            Expression::Number(_) => false,
+            Expression::Binary(a, _, b) => a.uses_old() || b.uses_old(),
+        }
+    }
+    pub fn preserves_congruence(&self) -> bool {
+        match self {
+            Expression::Old | Expression::Number(_) => true,
+            Expression::Binary(..) if !self.uses_old() => true,
+            Expression::Binary(a, operator, b) => {
+                matches!(operator, Operator::Add | Operator::Multiply)
+                    && a.preserves_congruence()
+                    && b.preserves_congruence()
+            }
+        }
+    }
+}
+
+fn tokenize<'a>(line: &Line<'a>, text: &'a str) -> Result<Vec<&'a str>> {
+    let mut tokens = vec![];
+    let mut rest = text.trim_start();
+    while let Some(character) = rest.chars().next() {
+        let length = if character.is_ascii_alphanumeric() {
+            rest.find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len())
+        } else if "+-*/%()".contains(character) {
+            1
+        } else {
+            return Err(line
+                .error(&rest[..character.len_utf8()], "unexpected character")
+                .into());
+        };
+        tokens.push(&rest[..length]);
+        rest = rest[length..].trim_start();
+    }
+    Ok(tokens)
+}
+
+struct Parser<'p, 'a> {
+    line: &'p Line<'a>,
+    tokens: &'p [&'a str],
+    end: &'a str,
+}
+
+impl<'a> Parser<'_, 'a> {
+    fn next(&mut self) -> Option<&'a str> {
+        let (&token, rest) = self.tokens.split_first()?;
+        self.tokens = rest;
+        Some(token)
+    }
+    fn expression(&mut self, min_precedence: u8) -> Result<Expression> {
+        let mut expression = self.operand()?;
+        while let Some(operator) = self
+            .tokens
+            .first()
+            .and_then(|token| Operator::from_symbol(token))
+            .filter(|operator| operator.precedence() >= min_precedence)
+        {
+            self.next();
+            let right = self.expression(operator.precedence() + 1)?;
+            expression = Expression::Binary(Box::new(expression), operator, Box::new(right));
+        }
+        Ok(expression)
+    }
+    fn operand(&mut self) -> Result<Expression> {
+        let Some(token) = self.next() else {
+            return Err(self
+                .line
+                .error(self.end, "expected \"old\", a number or \"(\"")
+                .into());
+        };
+        match token {
+            "old" => Ok(Expression::Old),
+            "(" => {
+                let expression = self.expression(1)?;
+                match self.next() {
+                    Some(")") => Ok(expression),
+                    Some(token) => Err(self.line.error(token, "expected \")\"").into()),
+                    None => Err(self.line.error(self.end, "expected \")\"").into()),
+                }
+            }
+            _ if token.starts_with(|c: char| c.is_ascii_digit()) => {
+                Ok(Expression::Number(self.line.parse(token)?))
+            }
+            _ => Err(self
+                .line
+                .error(token, "expected \"old\", a number or \"(\"")
+                .into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::parse::{self, ParseError};
+
+    fn parse(text: &str) -> Result<Expression> {
+        let line = parse::lines(text).next().unwrap();
+        Expression::parse(&line, line.text())
+    }
+
+    fn evaluate(text: &str, old: u64) -> u64 {
+        parse(text).unwrap().evaluate(old).unwrap()
+    }
+
+    fn error_column(text: &str) -> usize {
+        parse(text)
+            .unwrap_err()
+            .downcast::<ParseError>()
+            .unwrap()
+            .column()
+    }
+
+    #[test]
+    fn follows_precedence_and_parentheses() {
+        assert_eq!(evaluate("old * 19", 3), 57);
+        assert_eq!(evaluate("old + 2 * old", 5), 15);
+        assert_eq!(evaluate("(old + 2) * old", 5), 35);
+        assert_eq!(evaluate("old - 6 / 3 - 1", 10), 7);
+        assert_eq!(evaluate("old * old % 7 + (old - 1) / 2", 4), 3);
+    }
+
+    #[test]
+    fn reports_arithmetic_errors() {
+        let expression = parse("old / (old - 3)").unwrap();
+        let error = expression.evaluate(3).unwrap_err();
+        assert_eq!(error.to_string(), "worry level 3 / 0 divides by zero");
+        assert!(parse("old - 4").unwrap().evaluate(3).is_err());
+        assert!(parse("old * old").unwrap().evaluate(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn locates_syntax_errors() {
+        assert_eq!(error_column("old * (old + 1"), 15);
+        assert_eq!(error_column("old ^ 2"), 5);
+        assert_eq!(error_column("old * new"), 7);
+        assert_eq!(error_column("old old"), 5);
+        assert_eq!(error_column("old +"), 6);
+    }
+
+    #[test]
+    fn only_ring_operations_preserve_congruence() {
+        assert!(parse("(old + 3) * old").unwrap().preserves_congruence());
+        assert!(!parse("old - 3").unwrap().preserves_congruence());
+        assert!(!parse("old * 2 / 3").unwrap().preserves_congruence());
+        assert!(parse("old * (20 - 6 / 2)").unwrap().preserves_congruence());
+    }
+}
@@ -0,0 +1,339 @@
+mod expression;
+
+use anyhow::{anyhow, ensure, Context, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+use expression::Expression;
+use std::mem;
+
+#[derive(Clone, Copy)]
+struct Item {
+    worry_level: u64,
+}
+
+impl Item {
+    fn new(worry_level: u64) -> Self {
+        Self { worry_level }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: Vec<Item>,
+    operation: Expression,
+    divisible_denominator: u64,
+    monkey_true: usize,
+    monkey_false: usize,
+    bored_denominator: u64,
+    inspections: u64,
+}
+
+impl Monkey {
+    fn new(
+        items: Vec<Item>,
+        operation: Expression,
+        divisible_denominator: u64,
+        monkey_true: usize,
+        monkey_false: usize,
+        bored_denominator: u64,
+    ) -> Self {
+        let inspections = 0;
+        Self {
+            items,
+            operation,
+            divisible_denominator,
+            monkey_true,
+            monkey_false,
+            bored_denominator,
+            inspections,
+        }
+    }
+    fn inspect(&self, item: Item, modulus: Option<u64>) -> Result<(usize, Item)> {
+        let old_value = match modulus {
+            Some(modulus) if item.worry_level >= modulus => item.worry_level % modulus,
+            _ => item.worry_level,
+        };
+        let new_value = self.operation.evaluate(old_value)?;
+        let bored_value = match self.bored_denominator {
+            1 => new_value,
+            bored_denominator => new_value / bored_denominator,
+        };
+        let receiver_index = if bored_value.is_multiple_of(self.divisible_denominator) {
+            self.monkey_true
+        } else {
+            self.monkey_false
+        };
+        Ok((receiver_index, Item::new(bored_value)))
+    }
+    fn from_lines(lines: &[Line], bored_denominator: u64) -> Result<Self> {
+        let [header, items_line, operation_line, test_line, true_line, false_line] = lines else {
+            let first = lines.first().ok_or(anyhow!("empty monkey block"))?;
+            return Err(first
+                .error(
+                    first.text(),
+                    format!("expected 6 lines, got {}", lines.len()),
+                )
+                .into());
+        };
+        header.scan::<1>("Monkey {}:")?;
+
+        let items = items_line
+            .value_of("Starting items")?
+            .split(", ")
+            .map(|num_str| Ok(Item::new(items_line.parse(num_str)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let [operation] = operation_line.scan("Operation: new = {}")?;
+        let operation = Expression::parse(operation_line, operation)?;
+
+        let [divisible_denominator] = test_line.scan("Test: divisible by {}")?;
+        let divisible_denominator = test_line.parse(divisible_denominator)?;
+
+        let [monkey_true] = true_line.scan("If true: throw to monkey {}")?;
+        let monkey_true = true_line.parse(monkey_true)?;
+        let [monkey_false] = false_line.scan("If false: throw to monkey {}")?;
+        let monkey_false = false_line.parse(monkey_false)?;
+
+        Ok(Self::new(
+            items,
+            operation,
+            divisible_denominator,
+            monkey_true,
+            monkey_false,
+            bored_denominator,
+        ))
+    }
+}
+
+#[derive(Clone)]
+pub struct Monkeys {
+    collection: Vec<Monkey>,
+    reduce_worry_levels: bool,
+}
+
+impl Monkeys {
+    fn new(collection: Vec<Monkey>) -> Self {
+        Self {
+            collection,
+            reduce_worry_levels: true,
+        }
+    }
+    fn try_from_str(content: &str, bored_denominator: u64) -> Result<Self> {
+        let monkeys = parse::line_blocks(content)
+            .iter()
+            .map(|lines| Monkey::from_lines(lines, bored_denominator))
+            .collect::<Result<Vec<_>>>()?;
+        ensure!(
+            monkeys.len() >= 2,
+            "need at least 2 monkeys, got {}",
+            monkeys.len()
+        );
+        for (index, monkey) in monkeys.iter().enumerate() {
+            ensure!(
+                monkey.divisible_denominator != 0,
+                "monkey {} tests divisibility by 0",
+                index
+            );
+            for target in [monkey.monkey_true, monkey.monkey_false] {
+                ensure!(
+                    target < monkeys.len() && target != index,
+                    "monkey {} throws to monkey {}, which is not another monkey",
+                    index,
+                    target
+                );
+            }
+        }
+        monkeys
+            .iter()
+            .try_fold(1u64, |product, monkey| {
+                product.checked_mul(monkey.divisible_denominator)
+            })
+            .ok_or_else(|| anyhow!("product of the divisibility tests overflows"))?;
+        Ok(Monkeys::new(monkeys))
+    }
+    fn with_bored_denominator(mut self, bored_denominator: u64) -> Self {
+        for monkey in &mut self.collection {
+            monkey.bored_denominator = bored_denominator;
+        }
+        self
+    }
+    #[cfg(test)]
+    fn without_reduction(mut self) -> Self {
+        self.reduce_worry_levels = false;
+        self
+    }
+    fn get_all_divisible_denominators(&self) -> Vec<u64> {
+        self.collection
+            .iter()
+            .map(|monkey| monkey.divisible_denominator)
+            .collect::<Vec<_>>()
+    }
+    fn worry_modulus(&self) -> Option<u64> {
+        // Reducing modulo the product keeps every divisibility test intact, but
+        // only as long as nothing subtracts from or divides the worry level.
+        let reducible = self
+            .collection
+            .iter()
+            .all(|monkey| monkey.bored_denominator == 1 && monkey.operation.preserves_congruence());
+        (self.reduce_worry_levels && reducible)
+            .then(|| self.get_all_divisible_denominators().iter().product())
+    }
+    fn throw_all_items_for_monkey_at_index(
+        &mut self,
+        monkey_index: usize,
+        modulus: Option<u64>,
+    ) -> Result<()> {
+        let mut items = mem::take(&mut self.collection[monkey_index].items);
+        self.collection[monkey_index].inspections += items.len() as u64;
+        for item in items.drain(..) {
+            let (receiver_index, item) = self.collection[monkey_index]
+                .inspect(item, modulus)
+                .with_context(|| format!("monkey {} cannot inspect its items", monkey_index))?;
+            self.collection[receiver_index].items.push(item);
+        }
+        self.collection[monkey_index].items = items;
+        Ok(())
+    }
+    fn perform_round(&mut self, modulus: Option<u64>) -> Result<()> {
+        for monkey_index in 0..self.collection.len() {
+            self.throw_all_items_for_monkey_at_index(monkey_index, modulus)?;
+        }
+        Ok(())
+    }
+    fn perform_rounds(&mut self, count: u64) -> Result<()> {
+        let modulus = self.worry_modulus();
+        for round in 1..=count {
+            self.perform_round(modulus)
+                .with_context(|| format!("round {} failed", round))?;
+        }
+        Ok(())
+    }
+    fn inspections(&self) -> Vec<u64> {
+        self.collection
+            .iter()
+            .map(|monkey| monkey.inspections)
+            .collect()
+    }
+    fn task(mut self, rounds: u64) -> Result<u64> {
+        self.perform_rounds(rounds)?;
+        let mut inspections = self.inspections();
+        inspections.sort();
+        let [.., second, first] = inspections[..] else {
+            unreachable!("there are at least 2 monkeys");
+        };
+        first
+            .checked_mul(second)
+            .ok_or_else(|| anyhow!("monkey business {} * {} overflows", first, second))
+    }
+    fn task1(self) -> Result<u64> {
+        self.task(20)
+    }
+    fn task2(self) -> Result<u64> {
+        self.task(10_000)
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Monkeys;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Monkeys::try_from_str(input, 1)
+    }
+    fn part1(&self, monkeys: &Self::Input) -> Result<Answer> {
+        Ok(monkeys.clone().with_bored_denominator(3).task1()?.into())
+    }
+    fn part2(&self, monkeys: &Self::Input) -> Result<Answer> {
+        Ok(monkeys.clone().with_bored_denominator(1).task2()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../input2.txt");
+
+    fn monkeys(bored_denominator: u64) -> Monkeys {
+        Monkeys::try_from_str(EXAMPLE, bored_denominator).unwrap()
+    }
+
+    fn worry_levels(monkeys: &Monkeys) -> Vec<Vec<u64>> {
+        monkeys
+            .collection
+            .iter()
+            .map(|monkey| monkey.items.iter().map(|item| item.worry_level).collect())
+            .collect()
+    }
+
+    #[test]
+    fn reduction_keeps_throws_while_unreduced_fits() {
+        let modulus = monkeys(1)
+            .get_all_divisible_denominators()
+            .iter()
+            .product::<u64>();
+        let mut reduced = monkeys(1);
+        let mut unreduced = monkeys(1).without_reduction();
+        let mut rounds = 0;
+        while unreduced.perform_rounds(1).is_ok() {
+            reduced.perform_rounds(1).unwrap();
+            rounds += 1;
+            assert_eq!(reduced.inspections(), unreduced.inspections());
+            let expected = worry_levels(&unreduced)
+                .into_iter()
+                .map(|levels| levels.into_iter().map(|level| level % modulus).collect())
+                .collect::<Vec<Vec<_>>>();
+            let actual = worry_levels(&reduced)
+                .into_iter()
+                .map(|levels| levels.into_iter().map(|level| level % modulus).collect())
+                .collect::<Vec<Vec<_>>>();
+            assert_eq!(actual, expected);
+        }
+        assert!(rounds >= 3, "only {} rounds fit in a u64", rounds);
+    }
+
+    const SMALL_MODULUS: &str = "\
+Monkey 0:
+  Starting items: 100
+  Operation: new = old + 1
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 50
+  Operation: new = old * 2
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+
+    #[test]
+    fn reduction_is_skipped_when_bored() {
+        let mut bored = Monkeys::try_from_str(SMALL_MODULUS, 3).unwrap();
+        bored.perform_rounds(1).unwrap();
+        assert_eq!(worry_levels(&bored), vec![vec![33, 22], vec![]]);
+
+        let mut reduced = Monkeys::try_from_str(SMALL_MODULUS, 1).unwrap();
+        reduced.perform_rounds(1).unwrap();
+        let mut unreduced = Monkeys::try_from_str(SMALL_MODULUS, 1)
+            .unwrap()
+            .without_reduction();
+        unreduced.perform_rounds(1).unwrap();
+        assert_eq!(worry_levels(&unreduced), vec![vec![100, 202], vec![]]);
+        assert_eq!(worry_levels(&reduced), vec![vec![4, 10], vec![]]);
+    }
+
+    #[test]
+    fn unreduced_overflow_is_an_error() {
+        let error = monkeys(1)
+            .without_reduction()
+            .perform_rounds(20)
+            .unwrap_err();
+        let message = format!("{:#}", error);
+        assert!(message.starts_with("round "), "{}", message);
+        assert!(message.contains("overflows"), "{}", message);
+    }
+}
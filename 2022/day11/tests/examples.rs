@@ -0,0 +1,26 @@
+use aoc::{
+    testing::{assert_part, assert_parts},
+    Part,
+};
+use aoc2022_day11::Day11;
+
+#[test]
+fn example() {
+    assert_parts(&Day11, include_str!("../input2.txt"), 10605, 2713310158u64);
+}
+
+#[test]
+fn rewritten_operations() {
+    let input = include_str!("../input2.txt")
+        .replace("old * 19", "old * (20 - 1)")
+        .replace("old + 6", "(old + 2 * 3)")
+        .replace("old * old", "old * (old)")
+        .replace("old + 3", "3 + old");
+    assert_parts(&Day11, &input, 10605, 2713310158u64);
+}
+
+#[test]
+fn subtracting_operation() {
+    let input = include_str!("../input2.txt").replace("old * 19", "(old + 1) * 19 - 19");
+    assert_part(&Day11, &input, Part::One, 10605);
+}
@@ -0,0 +1,150 @@
+use anyhow::{anyhow, ensure, Error, Result};
+use aoc::{geometry::Point, grid::Grid, search, Answer, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node {
+    value: char,
+    coordinate: Point,
+}
+
+impl Node {
+    fn new(value: char, coord: Point) -> Self {
+        Self {
+            value,
+            coordinate: coord,
+        }
+    }
+    fn is_start(&self) -> bool {
+        self.value == 'S'
+    }
+    fn is_end(&self) -> bool {
+        self.value == 'E'
+    }
+    fn is_low_point(&self) -> bool {
+        self.height_value() == 'a'
+    }
+    fn height_value(&self) -> char {
+        match self.value {
+            'S' => 'a',
+            'E' => 'z',
+            chr => chr,
+        }
+    }
+    fn to_u8(&self) -> u8 {
+        self.height_value() as u8 - b'a'
+    }
+    fn is_walkable(&self, node: &Node) -> bool {
+        (node.to_u8() as i8 - self.to_u8() as i8) <= 1
+    }
+}
+
+pub struct Matrix {
+    node_matrix: Grid<Node>,
+    start_coord: Point,
+    end_coord: Point,
+}
+
+impl Matrix {
+    fn new(node_matrix: Grid<Node>, start_coord: Point, end_coord: Point) -> Self {
+        Self {
+            node_matrix,
+            start_coord,
+            end_coord,
+        }
+    }
+    fn get_node_for_coordinate(&self, coord: Point) -> Option<&Node> {
+        self.node_matrix.get_signed(coord)
+    }
+    fn get_start_node(&self) -> &Node {
+        self.get_node_for_coordinate(self.start_coord)
+            .expect("start node must exist")
+    }
+    fn get_end_node(&self) -> &Node {
+        self.get_node_for_coordinate(self.end_coord)
+            .expect("end node must exist")
+    }
+    fn get_edges_for_node(&self, node: &Node) -> Vec<&Node> {
+        node.coordinate
+            .neighbors()
+            .filter_map(|coord| self.get_node_for_coordinate(coord))
+            .filter(|edge_node| node.is_walkable(edge_node))
+            .collect()
+    }
+    fn find_path_to_end<'a>(
+        &'a self,
+        start_nodes: impl IntoIterator<Item = &'a Node>,
+    ) -> Option<Vec<&'a Node>> {
+        let end_node = self.get_end_node();
+        search::bfs(
+            start_nodes,
+            |node| self.get_edges_for_node(node),
+            |node| *node == end_node,
+        )
+        .goal_path()
+    }
+    fn find_all_low_nodes(&self) -> Vec<&Node> {
+        self.node_matrix
+            .values()
+            .filter(|node| node.is_low_point())
+            .collect()
+    }
+    fn task1(&self) -> Option<usize> {
+        let path = self.find_path_to_end([self.get_start_node()])?;
+        Some(path.len() - 1)
+    }
+    fn task2(&self) -> Option<usize> {
+        let path = self.find_path_to_end(self.find_all_low_nodes())?;
+        Some(path.len() - 1)
+    }
+}
+
+impl FromStr for Matrix {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let mut start_coord = None;
+        let mut end_coord = None;
+        let matrix = Grid::parse(content, |position, value| {
+            let node = Node::new(value, Point::from(position));
+            ensure!(
+                node.height_value().is_ascii_lowercase(),
+                "height must be a lowercase letter, S or E"
+            );
+            if node.is_start() {
+                start_coord = Some(node.coordinate)
+            } else if node.is_end() {
+                end_coord = Some(node.coordinate)
+            }
+            Ok(node)
+        })?;
+        match (start_coord, end_coord) {
+            (Some(start_coord_some), Some(end_coord_some)) => {
+                Ok(Self::new(matrix, start_coord_some, end_coord_some))
+            }
+            _ => Err(anyhow!("cannot find start or end")),
+        }
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Matrix;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Matrix::from_str(input)
+    }
+    fn part1(&self, matrix: &Self::Input) -> Result<Answer> {
+        let steps = matrix
+            .task1()
+            .ok_or(anyhow!("cannot find path from start to end"))?;
+        Ok(steps.into())
+    }
+    fn part2(&self, matrix: &Self::Input) -> Result<Answer> {
+        let steps = matrix
+            .task2()
+            .ok_or(anyhow!("cannot find path from any low point to end"))?;
+        Ok(steps.into())
+    }
+}
@@ -0,0 +1,16 @@
+use aoc::{parse::ParseError, testing::assert_parts, Solution};
+use aoc2022_day12::Day12;
+
+#[test]
+fn example() {
+    assert_parts(&Day12, include_str!("../input2.txt"), 31, 29);
+}
+
+#[test]
+fn invalid_heights_are_located() {
+    for input in ["SA\naE", "Sé\naE"] {
+        let error = Day12.parse(input).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line(), error.column()), (1, 2));
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::{anyhow, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+struct Card {
+    card_id: u32,
+    winning: HashSet<u32>,
+    numbers: Vec<u32>,
+}
+
+impl Card {
+    fn from_line(line: Line) -> Result<Self> {
+        let [card_id, winning, numbers] = line.scan("Card {}: {} | {}")?;
+        let card_id = line.parse(card_id)?;
+        let winning = line.numbers(winning)?.into_iter().collect();
+        let numbers = line.numbers(numbers)?;
+        Ok(Card::new(card_id, winning, numbers))
+    }
+    fn new(card_id: u32, winning: HashSet<u32>, numbers: Vec<u32>) -> Self {
+        Self {
+            card_id,
+            winning,
+            numbers,
+        }
+    }
+    fn get_winning_numbers(&self) -> Vec<u32> {
+        self.numbers
+            .iter()
+            .filter_map(|number| self.winning.contains(number).then_some(*number))
+            .collect_vec()
+    }
+    fn get_winning_numbers_count(&self) -> u32 {
+        self.get_winning_numbers().len() as u32
+    }
+    fn get_points(&self) -> Result<u32> {
+        let winning_numbers_count = self.get_winning_numbers_count();
+        if winning_numbers_count == 0 {
+            return Ok(0);
+        }
+        2_u32.checked_pow(winning_numbers_count - 1).ok_or_else(|| {
+            anyhow!(
+                "card {} with {} winning numbers is worth too many points",
+                self.card_id,
+                winning_numbers_count
+            )
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct Cards {
+    cards: HashMap<u32, Card>,
+}
+
+impl Cards {
+    fn new(cards: HashMap<u32, Card>) -> Self {
+        Self { cards }
+    }
+    fn try_from_str(content: &str) -> Result<Self> {
+        let cards = parse::lines(content)
+            .enumerate()
+            .map(|(index, line)| {
+                let card = Card::from_line(line)?;
+                if card.card_id as usize != index + 1 {
+                    return Err(line
+                        .error(line.text(), format!("expected card {}", index + 1))
+                        .into());
+                }
+                Ok((card.card_id, card))
+            })
+            .collect::<Result<HashMap<_, _>>>()?;
+        Ok(Cards::new(cards))
+    }
+    fn get_points(&self) -> Result<u32> {
+        self.cards.values().try_fold(0u32, |acc, card| {
+            acc.checked_add(card.get_points()?)
+                .ok_or_else(|| anyhow!("total points overflow"))
+        })
+    }
+    fn get_total_scratchcards_count(&self) -> Result<u32> {
+        let overflow = || anyhow!("scratchcard count overflows");
+        let mut card_counts = vec![1u32; self.cards.len()];
+        for i in 0..card_counts.len() {
+            let cur_card_count = card_counts[i];
+            let cur_card_id = i + 1;
+            let cur_card = &self.cards[&(cur_card_id as u32)];
+            for increment_index_diff in 1..=cur_card.get_winning_numbers_count() {
+                let increment_card_id = i + increment_index_diff as usize;
+                if increment_card_id >= card_counts.len() {
+                    break;
+                }
+                card_counts[increment_card_id] = card_counts[increment_card_id]
+                    .checked_add(cur_card_count)
+                    .ok_or_else(overflow)?;
+            }
+        }
+        card_counts
+            .into_iter()
+            .try_fold(0u32, |acc, count| acc.checked_add(count))
+            .ok_or_else(overflow)
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Cards;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Cards::try_from_str(input)
+    }
+    fn part1(&self, cards: &Self::Input) -> Result<Answer> {
+        Ok(cards.get_points()?.into())
+    }
+    fn part2(&self, cards: &Self::Input) -> Result<Answer> {
+        Ok(cards.get_total_scratchcards_count()?.into())
+    }
+}
@@ -0,0 +1,17 @@
+use aoc::{testing::assert_parts, Solution};
+use aoc2023_day04::Day04;
+
+#[test]
+fn example() {
+    assert_parts(&Day04, include_str!("../input2.txt"), 13, 30);
+}
+
+#[test]
+fn scratchcard_count_overflow_is_an_error() {
+    let input = (1..=60)
+        .map(|id| format!("Card {}: 1 2 3 4 5 | 1 2 3 4 5", id))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let cards = Day04.parse(&input).unwrap();
+    assert!(Day04.part2(&cards).is_err());
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+use aoc::{
+    interval::IntervalSet,
+    parse::{self, Line},
+    search, Answer, Solution,
+};
+use itertools::Itertools;
+
+type MapRange = (u64, u64, u64);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Segment {
+    start: u64,
+    end: u64,
+    offset: i128,
+}
+
+impl Segment {
+    fn shift(&self, value: u64) -> u64 {
+        (value as i128 + self.offset) as u64
+    }
+    fn image(&self) -> (u64, u64) {
+        (self.shift(self.start), self.shift(self.end))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PiecewiseMap {
+    segments: Vec<Segment>,
+}
+
+impl PiecewiseMap {
+    pub fn identity() -> Self {
+        Self::from_segments([Segment {
+            start: 0,
+            end: u64::MAX,
+            offset: 0,
+        }])
+    }
+    fn from_segments(segments: impl IntoIterator<Item = Segment>) -> Self {
+        let mut merged: Vec<Segment> = vec![];
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last) if last.offset == segment.offset => last.end = segment.end,
+                _ => merged.push(segment),
+            }
+        }
+        Self { segments: merged }
+    }
+    fn from_ranges(ranges: &[MapRange]) -> Result<Self> {
+        let mut unmapped = IntervalSet::from_range(..);
+        let mut segments = vec![];
+        for &(destination, source, length) in ranges {
+            if length == 0 {
+                continue;
+            }
+            let (Some(source_end), Some(_)) = (
+                source.checked_add(length - 1),
+                destination.checked_add(length - 1),
+            ) else {
+                bail!("map range {} {} {} overflows", destination, source, length);
+            };
+            let sources = IntervalSet::from_range(source..=source_end);
+            let offset = destination as i128 - source as i128;
+            for range in unmapped.intersection(&sources).ranges() {
+                segments.push(Segment {
+                    start: *range.start(),
+                    end: *range.end(),
+                    offset,
+                });
+            }
+            unmapped = unmapped.difference(&sources);
+        }
+        segments.extend(unmapped.ranges().map(|range| Segment {
+            start: *range.start(),
+            end: *range.end(),
+            offset: 0,
+        }));
+        segments.sort_by_key(|segment| segment.start);
+        Ok(Self::from_segments(segments))
+    }
+    fn overlapping(&self, start: u64, end: u64) -> impl Iterator<Item = Segment> + '_ {
+        let first = self.segments.partition_point(|segment| segment.end < start);
+        self.segments[first..]
+            .iter()
+            .take_while(move |segment| segment.start <= end)
+            .map(move |segment| Segment {
+                start: segment.start.max(start),
+                end: segment.end.min(end),
+                offset: segment.offset,
+            })
+    }
+    pub fn get(&self, value: u64) -> u64 {
+        let segment = self.overlapping(value, value).next();
+        segment.expect("segments cover every value").shift(value)
+    }
+    pub fn apply(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        values
+            .ranges()
+            .flat_map(|range| self.overlapping(*range.start(), *range.end()))
+            .map(|segment| {
+                let (start, end) = segment.image();
+                start..=end
+            })
+            .collect()
+    }
+    pub fn preimage(&self, values: &IntervalSet<u64>) -> IntervalSet<u64> {
+        self.segments
+            .iter()
+            .flat_map(|segment| {
+                let (start, end) = segment.image();
+                values
+                    .intersection(&IntervalSet::from_range(start..=end))
+                    .ranges()
+                    .map(|range| {
+                        let unshift = |value: u64| (value as i128 - segment.offset) as u64;
+                        unshift(*range.start())..=unshift(*range.end())
+                    })
+                    .collect_vec()
+            })
+            .collect()
+    }
+    pub fn then(&self, next: &Self) -> Self {
+        Self::from_segments(self.segments.iter().flat_map(|segment| {
+            let (start, end) = segment.image();
+            next.overlapping(start, end).map(|part| Segment {
+                start: (part.start as i128 - segment.offset) as u64,
+                end: (part.end as i128 - segment.offset) as u64,
+                offset: segment.offset + part.offset,
+            })
+        }))
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: HashMap<(String, String), PiecewiseMap>,
+}
+
+impl Almanac {
+    fn from_str(file_content: &str) -> Result<Self> {
+        let mut file_line_iter = parse::lines(file_content);
+
+        let seeds_line = file_line_iter
+            .next()
+            .ok_or_else(|| anyhow!("almanac is empty"))?;
+        let seeds = parse::numbers(seeds_line.value_of("seeds")?)
+            .map_err(|error| seeds_line.error(seeds_line.text(), error))?;
+
+        let mut blocks: Vec<(Line, Vec<MapRange>)> = vec![];
+        for line in file_line_iter {
+            if line.text().is_empty() {
+            } else if line.text().ends_with("map:") {
+                blocks.push((line, vec![]));
+            } else {
+                let numbers_line = line
+                    .numbers::<u64>(line.text())?
+                    .into_iter()
+                    .collect_tuple::<MapRange>()
+                    .ok_or_else(|| line.error(line.text(), "expected 3 numbers"))?;
+                blocks
+                    .last_mut()
+                    .ok_or_else(|| line.error(line.text(), "range outside of a map"))?
+                    .1
+                    .push(numbers_line)
+            }
+        }
+
+        let mut maps = HashMap::new();
+        for (header, ranges) in blocks {
+            let [from, to] = header.scan("{}-to-{} map:")?;
+            let map = PiecewiseMap::from_ranges(&ranges)
+                .map_err(|error| header.error(header.text(), error))?;
+            if maps
+                .insert((from.to_string(), to.to_string()), map)
+                .is_some()
+            {
+                return Err(header.error(header.text(), "duplicate map").into());
+            }
+        }
+
+        Ok(Self::new(seeds, maps))
+    }
+    fn new(seeds: Vec<u64>, maps: HashMap<(String, String), PiecewiseMap>) -> Self {
+        Self { seeds, maps }
+    }
+    pub fn chain(&self, from: &str, to: &str) -> Result<PiecewiseMap> {
+        let search = search::bfs(
+            [from.to_string()],
+            |category| {
+                self.maps
+                    .keys()
+                    .filter(|(source, _)| source == category)
+                    .map(|(_, destination)| destination.clone())
+                    .collect_vec()
+            },
+            |category| category == to,
+        );
+        let path = search
+            .goal_path()
+            .ok_or_else(|| anyhow!("no chain of maps leads from {} to {}", from, to))?;
+        Ok(path
+            .into_iter()
+            .tuple_windows()
+            .fold(PiecewiseMap::identity(), |map, step| {
+                map.then(&self.maps[&step])
+            }))
+    }
+    pub fn seeds_reaching(&self, location: u64) -> Result<IntervalSet<u64>> {
+        let map = self.chain("seed", "location")?;
+        Ok(map.preimage(&IntervalSet::from_range(location..=location)))
+    }
+    fn seed_points(&self) -> IntervalSet<u64> {
+        self.seeds.iter().map(|seed| *seed..=*seed).collect()
+    }
+    fn seed_ranges(&self) -> Result<IntervalSet<u64>> {
+        self.seeds
+            .chunks(2)
+            .map(|chunk| match *chunk {
+                [start, length] if length > 0 => start
+                    .checked_add(length - 1)
+                    .map(|end| start..=end)
+                    .ok_or_else(|| anyhow!("seed range {} {} overflows", start, length)),
+                _ => Err(anyhow!("invalid seed range {:?}", chunk)),
+            })
+            .collect()
+    }
+    fn lowest_location(&self, seeds: &IntervalSet<u64>) -> Result<u64> {
+        let map = self.chain("seed", "location")?;
+        map.apply(seeds)
+            .min()
+            .ok_or_else(|| anyhow!("there are no seeds"))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Almanac::from_str(input)
+    }
+    fn part1(&self, almanac: &Self::Input) -> Result<Answer> {
+        Ok(almanac.lowest_location(&almanac.seed_points())?.into())
+    }
+    fn part2(&self, almanac: &Self::Input) -> Result<Answer> {
+        Ok(almanac.lowest_location(&almanac.seed_ranges()?)?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map(ranges: &[(u64, u64, u64)]) -> PiecewiseMap {
+        PiecewiseMap::from_ranges(ranges).unwrap()
+    }
+
+    #[test]
+    fn maps_values_and_ranges() {
+        let seed_to_soil = map(&[(50, 98, 2), (52, 50, 48)]);
+        assert_eq!(
+            [0, 49, 50, 97, 98, 99, 100].map(|seed| seed_to_soil.get(seed)),
+            [0, 49, 52, 99, 50, 51, 100]
+        );
+        let soil = seed_to_soil.apply(&IntervalSet::from_range(45..100));
+        assert_eq!(soil.ranges().collect_vec(), vec![45..=99]);
+    }
+
+    #[test]
+    fn composition_matches_stepwise_mapping() {
+        let first = map(&[(50, 98, 2), (52, 50, 48)]);
+        let second = map(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+        let composed = first.then(&second);
+        for value in 0..120 {
+            assert_eq!(composed.get(value), second.get(first.get(value)));
+        }
+    }
+
+    #[test]
+    fn earlier_ranges_win_overlaps() {
+        let overlapping = map(&[(100, 0, 10), (200, 5, 10)]);
+        assert_eq!(overlapping.get(7), 107);
+        assert_eq!(overlapping.get(12), 207);
+        assert!(PiecewiseMap::from_ranges(&[(u64::MAX, 0, 2)]).is_err());
+        let to_the_top = map(&[(u64::MAX - 1, 0, 2), (0, 5, 0)]);
+        assert_eq!(to_the_top.get(1), u64::MAX);
+        assert_eq!(to_the_top.get(5), 5);
+    }
+
+    #[test]
+    fn preimage_inverts_apply() {
+        let first = map(&[(50, 98, 2), (52, 50, 48)]);
+        let targets = IntervalSet::from_range(50..=52);
+        let sources = first.preimage(&targets);
+        assert_eq!(sources.ranges().collect_vec(), vec![50..=50, 98..=99]);
+        assert_eq!(first.apply(&sources), targets);
+    }
+}
@@ -0,0 +1,19 @@
+use aoc::{testing::assert_parts, Solution};
+use aoc2023_day05::Day05;
+
+#[test]
+fn example() {
+    assert_parts(&Day05, include_str!("../input2.txt"), 35, 46);
+}
+
+#[test]
+fn seeds_reaching_a_location() {
+    let almanac = Day05.parse(include_str!("../input2.txt")).unwrap();
+    let seeds = almanac.seeds_reaching(35).unwrap();
+    assert!(seeds.contains(13));
+    assert!(almanac.seeds_reaching(46).unwrap().contains(82));
+    let soil_to_location = almanac.chain("soil", "location").unwrap();
+    let seed_to_soil = almanac.chain("seed", "soil").unwrap();
+    assert_eq!(soil_to_location.get(seed_to_soil.get(13)), 35);
+    assert!(almanac.chain("location", "seed").is_err());
+}
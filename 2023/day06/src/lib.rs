@@ -0,0 +1,165 @@
+use anyhow::{anyhow, ensure, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+use itertools::Itertools;
+use std::ops::RangeInclusive;
+
+fn race_lines(file_content: &str) -> Result<(Line<'_>, Line<'_>)> {
+    parse::lines(file_content)
+        .collect_tuple()
+        .ok_or_else(|| anyhow!("expected a time line and a distance line"))
+}
+
+#[derive(Debug)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+impl Race {
+    fn new(time: u64, distance: u64) -> Self {
+        Self { time, distance }
+    }
+    fn from_str(file_content: &str) -> Result<Self> {
+        let (time_line, distance_line) = race_lines(file_content)?;
+        let joined_number = |line: Line, key| -> Result<u64> {
+            let value = line.value_of(key)?;
+            line.parse(&value.split_whitespace().join(""))
+                .map_err(|_| line.error(value, "cannot join numbers into one").into())
+        };
+        Ok(Self::new(
+            joined_number(time_line, "Time")?,
+            joined_number(distance_line, "Distance")?,
+        ))
+    }
+    fn beats_record(&self, hold_time: u64) -> bool {
+        let distance = hold_time as u128 * (self.time - hold_time) as u128;
+        distance > self.distance as u128
+    }
+    pub fn winning_hold_times(&self) -> Option<RangeInclusive<u64>> {
+        // Holding h ms wins when h * (time - h) > distance, i.e. between the
+        // roots of h^2 - time * h + distance. The square root is floored and
+        // the record must be beaten strictly, so nudge the bound until it is
+        // the first winning hold time.
+        let (time, distance) = (self.time as u128, self.distance as u128);
+        let discriminant = (time * time).checked_sub(4 * distance)?;
+        let middle = self.time / 2;
+        let mut min = ((time - discriminant.isqrt()) / 2) as u64;
+        while min <= middle && !self.beats_record(min) {
+            min += 1;
+        }
+        while min > 0 && self.beats_record(min - 1) {
+            min -= 1;
+        }
+        (min <= middle).then(|| min..=self.time - min)
+    }
+    pub fn min_winning_hold_time(&self) -> Option<u64> {
+        self.winning_hold_times()
+            .map(|hold_times| *hold_times.start())
+    }
+    pub fn max_winning_hold_time(&self) -> Option<u64> {
+        self.winning_hold_times()
+            .map(|hold_times| *hold_times.end())
+    }
+    pub fn winning_hold_times_count(&self) -> u64 {
+        self.winning_hold_times()
+            .map_or(0, |hold_times| hold_times.end() - hold_times.start() + 1)
+    }
+}
+
+#[derive(Debug)]
+pub struct Races {
+    races: Vec<Race>,
+}
+
+impl Races {
+    fn new(races: Vec<Race>) -> Self {
+        Self { races }
+    }
+    fn from_str(file_content: &str) -> Result<Self> {
+        let (time_line, distance_line) = race_lines(file_content)?;
+        let time_numbers = time_line.numbers::<u64>(time_line.value_of("Time")?)?;
+        let distance_numbers = distance_line.numbers::<u64>(distance_line.value_of("Distance")?)?;
+        ensure!(
+            time_numbers.len() == distance_numbers.len(),
+            "{} race times but {} distances",
+            time_numbers.len(),
+            distance_numbers.len()
+        );
+        let races_vec = time_numbers
+            .into_iter()
+            .zip(distance_numbers)
+            .map(|(race_time, race_distance)| Race::new(race_time, race_distance))
+            .collect::<Vec<_>>();
+        Ok(Self::new(races_vec))
+    }
+    fn winning_hold_times_count_multiplied(&self) -> Result<u64> {
+        self.races.iter().try_fold(1u64, |product, race| {
+            product
+                .checked_mul(race.winning_hold_times_count())
+                .ok_or_else(|| anyhow!("product of winning hold time counts overflows"))
+        })
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Races, Race);
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok((Races::from_str(input)?, Race::from_str(input)?))
+    }
+    fn part1(&self, (races, _): &Self::Input) -> Result<Answer> {
+        Ok(races.winning_hold_times_count_multiplied()?.into())
+    }
+    fn part2(&self, (_, race): &Self::Input) -> Result<Answer> {
+        Ok(race.winning_hold_times_count().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn brute_force(race: &Race) -> Vec<u64> {
+        (0..=race.time)
+            .filter(|&hold_time| hold_time * (race.time - hold_time) > race.distance)
+            .collect()
+    }
+
+    #[test]
+    fn example_races() {
+        let race = Race::new(30, 200);
+        assert_eq!(race.winning_hold_times(), Some(11..=19));
+        assert_eq!(Race::new(7, 9).min_winning_hold_time(), Some(2));
+        assert_eq!(Race::new(7, 9).max_winning_hold_time(), Some(5));
+    }
+
+    #[test]
+    fn record_on_the_vertex_is_not_beaten() {
+        assert_eq!(Race::new(10, 25).winning_hold_times(), None);
+        assert_eq!(Race::new(10, 24).winning_hold_times(), Some(5..=5));
+    }
+
+    #[test]
+    fn huge_race_does_not_overflow() {
+        let race = Race::new(u64::MAX, u64::MAX);
+        assert_eq!(race.min_winning_hold_time(), Some(2));
+        assert_eq!(race.max_winning_hold_time(), Some(u64::MAX - 2));
+    }
+
+    proptest! {
+        #[test]
+        fn matches_brute_force(time in 0u64..300, distance in 0u64..25_000) {
+            let race = Race::new(time, distance);
+            let winning = brute_force(&race);
+            prop_assert_eq!(race.winning_hold_times_count(), winning.len() as u64);
+            prop_assert_eq!(race.min_winning_hold_time(), winning.first().copied());
+            prop_assert_eq!(race.max_winning_hold_time(), winning.last().copied());
+        }
+    }
+}
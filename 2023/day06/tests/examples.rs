@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2023_day06::Day06;
+
+#[test]
+fn example() {
+    assert_parts(&Day06, include_str!("../input2.txt"), 288, 71503);
+}
@@ -0,0 +1,388 @@
+use anyhow::{anyhow, ensure, Error, Result};
+use aoc::{
+    parse::{self, Line},
+    Answer, Solution,
+};
+use itertools::Itertools;
+use std::{fmt::Write, str::FromStr};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct HandType {
+    counts: Vec<usize>,
+}
+
+impl HandType {
+    fn from_strengths(strengths: &[usize]) -> Self {
+        let wildcards = strengths.iter().filter(|&&strength| strength == 0).count();
+        let mut counts = strengths
+            .iter()
+            .filter(|&&strength| strength != 0)
+            .counts()
+            .into_values()
+            .sorted()
+            .rev()
+            .collect_vec();
+        match counts.first_mut() {
+            Some(highest) => *highest += wildcards,
+            None => counts.push(wildcards),
+        }
+        Self { counts }
+    }
+    pub fn name(&self) -> String {
+        match self.counts.as_slice() {
+            [5] => "five of a kind".to_string(),
+            [4, 1] => "four of a kind".to_string(),
+            [3, 2] => "full house".to_string(),
+            [3, 1, 1] => "three of a kind".to_string(),
+            [2, 2, 1] => "two pair".to_string(),
+            [2, 1, 1, 1] => "one pair".to_string(),
+            [1, 1, 1, 1, 1] => "high card".to_string(),
+            counts => counts.iter().join("+"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TieBreak {
+    Positional,
+    Sorted,
+}
+
+impl FromStr for TieBreak {
+    type Err = Error;
+
+    fn from_str(string: &str) -> Result<Self> {
+        match string {
+            "positional" => Ok(Self::Positional),
+            "sorted" => Ok(Self::Sorted),
+            _ => Err(anyhow!(
+                "unknown tie-break {:?}, expected positional or sorted",
+                string
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CardRules {
+    order: Vec<char>,
+    wildcards: Vec<char>,
+    tie_break: TieBreak,
+}
+
+impl CardRules {
+    pub fn new(order: &str, wildcards: &str, tie_break: TieBreak) -> Result<Self> {
+        let order = order.chars().collect_vec();
+        let wildcards = wildcards.chars().collect_vec();
+        ensure!(order.iter().all_unique(), "card order repeats a label");
+        if let Some(wildcard) = wildcards.iter().find(|label| !order.contains(label)) {
+            return Err(anyhow!("wildcard {:?} is not in the card order", wildcard));
+        }
+        Ok(Self {
+            order,
+            wildcards,
+            tie_break,
+        })
+    }
+    pub fn standard() -> Self {
+        Self::new("23456789TJQKA", "", TieBreak::Positional).expect("standard rules are valid")
+    }
+    pub fn jokers() -> Self {
+        Self::new("23456789TJQKA", "J", TieBreak::Positional).expect("joker rules are valid")
+    }
+    fn strength(&self, label: char) -> Result<usize> {
+        if self.wildcards.contains(&label) {
+            return Ok(0);
+        }
+        self.order
+            .iter()
+            .position(|&other| other == label)
+            .map(|position| position + 1)
+            .ok_or_else(|| anyhow!("card {:?} is not in the card order", label))
+    }
+    fn rank_key(&self, hand: &Hand) -> Result<(HandType, Vec<usize>)> {
+        let strengths = hand
+            .labels
+            .iter()
+            .map(|&label| self.strength(label))
+            .collect::<Result<Vec<_>>>()?;
+        let hand_type = HandType::from_strengths(&strengths);
+        let tie_break = match self.tie_break {
+            TieBreak::Positional => strengths,
+            TieBreak::Sorted => strengths.into_iter().sorted().rev().collect(),
+        };
+        Ok((hand_type, tie_break))
+    }
+    pub fn hand_type(&self, hand: &str) -> Result<HandType> {
+        Ok(self.rank_key(&Hand::from_str(hand)?)?.0)
+    }
+    fn substitute_wildcards(&self, hand: &Hand) -> String {
+        let best = hand
+            .labels
+            .iter()
+            .filter(|label| !self.wildcards.contains(label))
+            .counts()
+            .into_iter()
+            .max_by_key(|&(&label, count)| (count, self.strength(label).ok()))
+            .map(|(&label, _)| label)
+            .or_else(|| self.order.last().copied());
+        hand.labels
+            .iter()
+            .map(|label| match best {
+                Some(best) if self.wildcards.contains(label) => best,
+                _ => *label,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hand {
+    labels: Vec<char>,
+}
+
+impl Hand {
+    fn new(labels: Vec<char>) -> Self {
+        Self { labels }
+    }
+    fn from_str(string: &str) -> Result<Self> {
+        ensure!(!string.is_empty(), "hand has no cards");
+        Ok(Self::new(string.chars().collect()))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct HandBid {
+    hand: Hand,
+    bid: u64,
+}
+
+impl HandBid {
+    fn new(hand: Hand, bid: u64) -> Self {
+        Self { hand, bid }
+    }
+    fn from_line(line: Line) -> Result<Self> {
+        let (hand_str, bid_str) = line
+            .text()
+            .split_whitespace()
+            .collect_tuple()
+            .ok_or_else(|| line.error(line.text(), "expected a hand and a bid"))?;
+        let hand = Hand::from_str(hand_str).map_err(|error| line.error(hand_str, error))?;
+        let bid = line.parse(bid_str)?;
+        Ok(Self::new(hand, bid))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HandBids {
+    hand_bids: Vec<HandBid>,
+}
+
+impl HandBids {
+    fn new(hand_bids: Vec<HandBid>) -> Self {
+        Self { hand_bids }
+    }
+    pub fn standings(&self, rules: &CardRules) -> Result<Vec<Standing>> {
+        let keys = self
+            .hand_bids
+            .iter()
+            .map(|hand_bid| rules.rank_key(&hand_bid.hand))
+            .collect::<Result<Vec<_>>>()?;
+        self.hand_bids
+            .iter()
+            .zip(keys)
+            .sorted_by(|(_, a), (_, b)| a.cmp(b))
+            .enumerate()
+            .map(|(i, (hand_bid, (hand_type, _)))| {
+                let rank = i as u64 + 1;
+                let winnings = hand_bid.bid.checked_mul(rank).ok_or_else(|| {
+                    anyhow!("winnings of bid {} at rank {} overflow", hand_bid.bid, rank)
+                })?;
+                Ok(Standing {
+                    rank,
+                    hand: hand_bid.hand.labels.iter().collect(),
+                    hand_type,
+                    substituted: rules.substitute_wildcards(&hand_bid.hand),
+                    bid: hand_bid.bid,
+                    winnings,
+                })
+            })
+            .collect()
+    }
+    pub fn winnings(&self, rules: &CardRules) -> Result<u64> {
+        self.standings(rules)?
+            .iter()
+            .try_fold(0u64, |acc, standing| acc.checked_add(standing.winnings))
+            .ok_or_else(|| anyhow!("total winnings overflow"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub rank: u64,
+    pub hand: String,
+    pub hand_type: HandType,
+    pub substituted: String,
+    pub bid: u64,
+    pub winnings: u64,
+}
+
+pub fn render_table(standings: &[Standing]) -> String {
+    let hand_width = standings
+        .iter()
+        .map(|standing| standing.hand.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("hand".len());
+    let mut output = String::new();
+    writeln!(
+        output,
+        "{:>5}  {:<hand_width$}  {:<hand_width$}  {:<15}  {:>6}  {:>10}",
+        "rank",
+        "hand",
+        "as",
+        "type",
+        "bid",
+        "winnings",
+        hand_width = hand_width
+    )
+    .unwrap();
+    for standing in standings {
+        writeln!(
+            output,
+            "{:>5}  {:<hand_width$}  {:<hand_width$}  {:<15}  {:>6}  {:>10}",
+            standing.rank,
+            standing.hand,
+            standing.substituted,
+            standing.hand_type.name(),
+            standing.bid,
+            standing.winnings,
+            hand_width = hand_width
+        )
+        .unwrap();
+    }
+    output
+}
+
+pub fn render_csv(standings: &[Standing]) -> String {
+    let mut output = String::from("rank,hand,as,type,bid,winnings\n");
+    for standing in standings {
+        writeln!(
+            output,
+            "{},{},{},{},{},{}",
+            standing.rank,
+            standing.hand,
+            standing.substituted,
+            standing.hand_type.name(),
+            standing.bid,
+            standing.winnings
+        )
+        .unwrap();
+    }
+    output
+}
+
+impl FromStr for HandBids {
+    type Err = Error;
+
+    fn from_str(content: &str) -> Result<Self> {
+        let mut hand_size = None;
+        let hand_bids = parse::each_line(content, |line| {
+            let hand_bid = HandBid::from_line(line)?;
+            let size = hand_bid.hand.labels.len();
+            match *hand_size.get_or_insert(size) {
+                expected if expected != size => Err(line
+                    .error(
+                        line.text(),
+                        format!("hand has {} cards, expected {}", size, expected),
+                    )
+                    .into()),
+                _ => Ok(hand_bid),
+            }
+        })?;
+        Ok(Self::new(hand_bids))
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = HandBids;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        input.parse()
+    }
+    fn part1(&self, hand_bids: &Self::Input) -> Result<Answer> {
+        Ok(hand_bids.winnings(&CardRules::standard())?.into())
+    }
+    fn part2(&self, hand_bids: &Self::Input) -> Result<Answer> {
+        Ok(hand_bids.winnings(&CardRules::jokers())?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_name(rules: &CardRules, hand: &str) -> String {
+        rules.hand_type(hand).unwrap().name()
+    }
+
+    #[test]
+    fn classifies_hands() {
+        let rules = CardRules::standard();
+        assert_eq!(type_name(&rules, "32T3K"), "one pair");
+        assert_eq!(type_name(&rules, "KTJJT"), "two pair");
+        assert_eq!(type_name(&rules, "T55J5"), "three of a kind");
+        assert_eq!(type_name(&rules, "23432"), "two pair");
+        assert_eq!(type_name(&rules, "AAAAK"), "four of a kind");
+        assert!(rules.hand_type("2345X").is_err());
+    }
+
+    #[test]
+    fn wildcards_join_the_largest_group() {
+        let rules = CardRules::jokers();
+        assert_eq!(type_name(&rules, "KTJJT"), "four of a kind");
+        assert_eq!(type_name(&rules, "JJJJJ"), "five of a kind");
+        assert_eq!(type_name(&rules, "2345J"), "one pair");
+        assert_eq!(type_name(&rules, "22J33"), "full house");
+    }
+
+    #[test]
+    fn other_hand_sizes() {
+        let rules = CardRules::standard();
+        assert_eq!(type_name(&rules, "AA2"), "2+1");
+        assert!(rules.hand_type("AAA2").unwrap() > rules.hand_type("AA22").unwrap());
+        assert!("AA2 1\nAA22 2".parse::<HandBids>().is_err());
+    }
+
+    #[test]
+    fn standings_show_substitutions() {
+        let hand_bids = "KTJJT 220\nQQQJA 483\nJJJJJ 5".parse::<HandBids>().unwrap();
+        let standings = hand_bids.standings(&CardRules::jokers()).unwrap();
+        assert_eq!(
+            render_csv(&standings),
+            "rank,hand,as,type,bid,winnings\n\
+             1,QQQJA,QQQQA,four of a kind,483,483\n\
+             2,KTJJT,KTTTT,four of a kind,220,440\n\
+             3,JJJJJ,AAAAA,five of a kind,5,15\n"
+        );
+        let table = render_table(&standings);
+        let width = table.lines().next().unwrap().len();
+        assert!(table.lines().all(|line| line.len() == width));
+    }
+
+    #[test]
+    fn table_columns_align_for_short_hands() {
+        let hand_bids = "AA2 7\nKKK 30".parse::<HandBids>().unwrap();
+        let table = render_table(&hand_bids.standings(&CardRules::standard()).unwrap());
+        let width = table.lines().next().unwrap().len();
+        assert!(table.lines().all(|line| line.len() == width));
+    }
+
+    #[test]
+    fn rejects_inconsistent_rules() {
+        assert!(CardRules::new("23A3", "", TieBreak::Positional).is_err());
+        assert!(CardRules::new("23A", "J", TieBreak::Positional).is_err());
+    }
+}
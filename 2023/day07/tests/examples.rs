@@ -0,0 +1,20 @@
+use aoc::testing::assert_parts;
+use aoc2023_day07::{CardRules, Day07, HandBids, TieBreak};
+
+#[test]
+fn example() {
+    assert_parts(&Day07, include_str!("../input2.txt"), 6440, 5905);
+}
+
+#[test]
+fn variant_rules() {
+    let hand_bids = "2A 10\nK3 100".parse::<HandBids>().unwrap();
+    let positional = CardRules::new("23456789TJQKA", "", TieBreak::Positional).unwrap();
+    let sorted = CardRules::new("23456789TJQKA", "", TieBreak::Sorted).unwrap();
+    assert_eq!(hand_bids.winnings(&positional).unwrap(), 210);
+    assert_eq!(hand_bids.winnings(&sorted).unwrap(), 120);
+
+    let wild_twos = CardRules::new("23456789TJQKA", "2", TieBreak::Positional).unwrap();
+    let hand_bids = "2Q 1\nKK 10\n33 100".parse::<HandBids>().unwrap();
+    assert_eq!(hand_bids.winnings(&wild_twos).unwrap(), 1 + 200 + 30);
+}
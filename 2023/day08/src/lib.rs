@@ -0,0 +1,338 @@
+use anyhow::{anyhow, ensure, Result};
+use aoc::{geometry::Direction, parse, Answer, Solution};
+use itertools::Itertools;
+use num::integer::{ExtendedGcd, Integer};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+struct Node {
+    target_label_left: String,
+    target_label_right: String,
+}
+
+impl Node {
+    fn new(target_label_left: String, target_label_right: String) -> Self {
+        Self {
+            target_label_left,
+            target_label_right,
+        }
+    }
+    fn target_label(&self, direction: Direction) -> &str {
+        if direction == Direction::Left {
+            &self.target_label_left
+        } else {
+            &self.target_label_right
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    offset: u64,
+    length: u64,
+    hits_before: Vec<u64>,
+    hits: Vec<u64>,
+}
+
+impl Cycle {
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+    pub fn length(&self) -> u64 {
+        self.length
+    }
+    pub fn hits_before(&self) -> &[u64] {
+        &self.hits_before
+    }
+    pub fn hits(&self) -> &[u64] {
+        &self.hits
+    }
+    fn hits_at(&self, step: u64) -> bool {
+        if step < self.offset {
+            return self.hits_before.contains(&step);
+        }
+        let phase = (step - self.offset) % self.length;
+        self.hits.contains(&(self.offset + phase))
+    }
+    fn lcm_violation(&self) -> Option<String> {
+        match (self.hits_before.as_slice(), self.hits.as_slice()) {
+            ([], [hit]) if *hit == self.length => None,
+            (_, []) => Some("never reaches an end node inside its cycle".to_string()),
+            _ => Some(format!(
+                "reaches end nodes at steps {:?} before and {:?} inside a {}-step cycle \
+                 that starts at step {}",
+                self.hits_before, self.hits, self.length, self.offset
+            )),
+        }
+    }
+}
+
+fn combine_residues(a: i128, m: i128, b: i128, n: i128) -> Result<Option<(i128, i128)>> {
+    let overflow = || anyhow!("cycle arithmetic overflows");
+    let ExtendedGcd { gcd, x, .. } = m.extended_gcd(&n);
+    if (b - a) % gcd != 0 {
+        return Ok(None);
+    }
+    let n_reduced = n / gcd;
+    let lcm = (m / gcd).checked_mul(n).ok_or_else(overflow)?;
+    let k = ((b - a) / gcd % n_reduced)
+        .checked_mul(x % n_reduced)
+        .ok_or_else(overflow)?
+        % n_reduced;
+    let combined = m.checked_mul(k).ok_or_else(overflow)? + a;
+    Ok(Some((combined.rem_euclid(lcm), lcm)))
+}
+
+const MAX_RESIDUES: usize = 100_000;
+
+fn first_common_hit(cycles: &[Cycle]) -> Result<u64> {
+    let max_offset = cycles
+        .iter()
+        .map(|cycle| cycle.offset)
+        .max()
+        .ok_or_else(|| anyhow!("there are no walkers"))?;
+    let early_hit = cycles
+        .iter()
+        .flat_map(|cycle| cycle.hits_before.iter().copied())
+        .filter(|&step| step < max_offset)
+        .sorted()
+        .find(|&step| cycles.iter().all(|cycle| cycle.hits_at(step)));
+    if let Some(step) = early_hit {
+        return Ok(step);
+    }
+
+    let mut modulus = 1i128;
+    let mut residues = vec![0i128];
+    for cycle in cycles {
+        let length = cycle.length as i128;
+        let mut next_residues = vec![];
+        let mut next_modulus = modulus;
+        for &residue in &residues {
+            for &hit in &cycle.hits {
+                if let Some((combined, lcm)) =
+                    combine_residues(residue, modulus, hit as i128 % length, length)?
+                {
+                    next_residues.push(combined);
+                    next_modulus = lcm;
+                }
+            }
+        }
+        ensure!(
+            next_residues.len() <= MAX_RESIDUES,
+            "too many combinations of end node hits to check"
+        );
+        next_residues.sort();
+        next_residues.dedup();
+        residues = next_residues;
+        modulus = next_modulus;
+    }
+
+    let max_offset = max_offset as i128;
+    let first_step = residues
+        .into_iter()
+        .map(|residue| {
+            let behind = (max_offset - residue).max(0);
+            residue + (behind + modulus - 1) / modulus * modulus
+        })
+        .min()
+        .ok_or_else(|| anyhow!("the walkers never stand on end nodes at the same time"))?;
+    u64::try_from(first_step).map_err(|_| anyhow!("step count {} overflows", first_step))
+}
+
+struct Network {
+    nodes: HashMap<String, Node>,
+}
+
+impl Network {
+    fn new(nodes: HashMap<String, Node>) -> Self {
+        Self { nodes }
+    }
+    fn cycle(
+        &self,
+        directions: &Directions,
+        start_label: &str,
+        is_end: impl Fn(&str) -> bool,
+    ) -> Result<Cycle> {
+        ensure!(
+            self.nodes.contains_key(start_label),
+            "node {} does not exist",
+            start_label
+        );
+        let direction_count = directions.directions.len() as u64;
+        let mut seen = HashMap::new();
+        let mut hits = vec![];
+        let mut label = start_label;
+        let mut step = 0;
+        loop {
+            let index = (step % direction_count) as usize;
+            if let Some(&offset) = seen.get(&(label, index)) {
+                let (hits_before, hits) = hits.into_iter().partition(|&hit| hit < offset);
+                return Ok(Cycle {
+                    offset,
+                    length: step - offset,
+                    hits_before,
+                    hits,
+                });
+            }
+            seen.insert((label, index), step);
+            if is_end(label) {
+                hits.push(step);
+            }
+            label = self.nodes[label].target_label(directions.directions[index]);
+            step += 1;
+        }
+    }
+    fn labels_ending_with_char(&self, character: char) -> Vec<&str> {
+        self.nodes
+            .keys()
+            .filter(|label| label.ends_with(character))
+            .map(String::as_str)
+            .sorted()
+            .collect_vec()
+    }
+}
+
+struct Directions {
+    directions: Vec<Direction>,
+}
+
+impl Directions {
+    fn new(directions: Vec<Direction>) -> Self {
+        Self { directions }
+    }
+}
+
+pub struct NetworkWithDirections {
+    network: Network,
+    directions: Directions,
+}
+
+impl NetworkWithDirections {
+    fn new(network: Network, directions: Directions) -> Self {
+        Self {
+            network,
+            directions,
+        }
+    }
+    fn from_str(content: &str) -> Result<Self> {
+        let mut lines_iter = parse::lines(content);
+
+        let directions_line = lines_iter
+            .next()
+            .ok_or_else(|| anyhow!("Cannot read directions from file"))?;
+        let directions_vec = directions_line
+            .text()
+            .chars()
+            .map(|character| match character {
+                'L' => Ok(Direction::Left),
+                'R' => Ok(Direction::Right),
+                _ => Err(anyhow!("direction must be L or R, got {:?}", character)),
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|error| directions_line.error(directions_line.text(), error))?;
+        if directions_vec.is_empty() {
+            return Err(directions_line
+                .error(directions_line.text(), "no directions")
+                .into());
+        }
+        let directions = Directions::new(directions_vec);
+
+        if let Some(separator) = lines_iter.next() {
+            if !separator.text().is_empty() {
+                return Err(separator
+                    .error(
+                        separator.text(),
+                        "expected a blank line after the directions",
+                    )
+                    .into());
+            }
+        }
+
+        let mut node_lines = HashMap::new();
+        for line in lines_iter {
+            let [label, target_label_left, target_label_right] = line.scan("{} = ({}, {})")?;
+            let node = Node::new(
+                target_label_left.to_string(),
+                target_label_right.to_string(),
+            );
+            if node_lines.insert(label.to_string(), (line, node)).is_some() {
+                return Err(line
+                    .error(label, format!("node {} is defined twice", label))
+                    .into());
+            }
+        }
+        for (line, node) in node_lines.values() {
+            for target in [&node.target_label_left, &node.target_label_right] {
+                if !node_lines.contains_key(target) {
+                    return Err(line
+                        .error(line.text(), format!("node {} does not exist", target))
+                        .into());
+                }
+            }
+        }
+        let nodes_vec = node_lines
+            .into_iter()
+            .map(|(label, (_, node))| (label, node))
+            .collect();
+        let network = Network::new(nodes_vec);
+
+        Ok(NetworkWithDirections::new(network, directions))
+    }
+    fn walk_from_aaa_to_zzz_count(&self) -> Result<u64> {
+        let cycle = self
+            .network
+            .cycle(&self.directions, "AAA", |label| label == "ZZZ")?;
+        ensure!(
+            !cycle.hits_before.is_empty() || !cycle.hits.is_empty(),
+            "walking from AAA never reaches ZZZ"
+        );
+        first_common_hit(&[cycle])
+    }
+    pub fn ghost_cycles(&self) -> Result<Vec<(String, Cycle)>> {
+        self.network
+            .labels_ending_with_char('A')
+            .into_iter()
+            .map(|label| {
+                let cycle = self
+                    .network
+                    .cycle(&self.directions, label, |label| label.ends_with('Z'))?;
+                Ok((label.to_string(), cycle))
+            })
+            .collect()
+    }
+    pub fn lcm_violations(&self) -> Result<Vec<String>> {
+        Ok(self
+            .ghost_cycles()?
+            .into_iter()
+            .filter_map(|(label, cycle)| {
+                let violation = cycle.lcm_violation()?;
+                Some(format!("ghost from {} {}", label, violation))
+            })
+            .collect())
+    }
+    fn walk_from_xxa_to_xxz_count(&self) -> Result<u64> {
+        let cycles = self
+            .ghost_cycles()?
+            .into_iter()
+            .map(|(_, cycle)| cycle)
+            .collect_vec();
+        ensure!(!cycles.is_empty(), "there are no nodes ending with A");
+        first_common_hit(&cycles)
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = NetworkWithDirections;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        NetworkWithDirections::from_str(input)
+    }
+    fn part1(&self, network_with_directions: &Self::Input) -> Result<Answer> {
+        Ok(network_with_directions.walk_from_aaa_to_zzz_count()?.into())
+    }
+    fn part2(&self, network_with_directions: &Self::Input) -> Result<Answer> {
+        Ok(network_with_directions.walk_from_xxa_to_xxz_count()?.into())
+    }
+}
@@ -0,0 +1,88 @@
+use aoc::{parse::ParseError, testing::assert_part, Part, Solution};
+use aoc2023_day08::Day08;
+
+#[test]
+fn example() {
+    assert_part(&Day08, include_str!("../input2.txt"), Part::One, 2);
+}
+
+#[test]
+fn repeated_directions_example() {
+    assert_part(&Day08, include_str!("../input3.txt"), Part::One, 6);
+}
+
+#[test]
+fn ghosts_example() {
+    assert_part(&Day08, include_str!("../input4.txt"), Part::Two, 6);
+}
+
+#[test]
+fn ghost_cycles() {
+    let network = Day08.parse(include_str!("../input4.txt")).unwrap();
+    let cycles = network.ghost_cycles().unwrap();
+    let summary = cycles
+        .iter()
+        .map(|(label, cycle)| {
+            (
+                label.as_str(),
+                cycle.offset(),
+                cycle.length(),
+                cycle.hits().to_vec(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        summary,
+        vec![("11A", 1, 2, vec![2]), ("22A", 1, 6, vec![3, 6])]
+    );
+    let violations = network.lcm_violations().unwrap();
+    assert_eq!(violations.len(), 1);
+    assert!(violations[0].starts_with("ghost from 22A"));
+}
+
+#[test]
+fn ghosts_with_offset_cycles() {
+    let input = "\
+L
+
+11A = (11B, 11B)
+11B = (11Z, 11Z)
+11Z = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22B, 22B)
+22B = (22C, 22C)
+22C = (22Z, 22Z)";
+    assert_part(&Day08, input, Part::Two, 4);
+    let network = Day08.parse(input).unwrap();
+    assert_eq!(network.lcm_violations().unwrap().len(), 1);
+}
+
+#[test]
+fn ghosts_meeting_before_their_cycles() {
+    let input = "\
+L
+
+11A = (11Z, 11Z)
+11Z = (11B, 11B)
+11B = (11B, 11B)
+22A = (22Z, 22Z)
+22Z = (22Z, 22Z)";
+    assert_part(&Day08, input, Part::Two, 1);
+}
+
+#[test]
+fn malformed_networks_are_located() {
+    let cases = [
+        ("<>L\n\nAAA = (AAA, AAA)", (1, 1)),
+        ("L\nAAA = (AAA, AAA)", (2, 1)),
+        (
+            "L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nAAA = (BBB, AAA)",
+            (5, 1),
+        ),
+    ];
+    for (input, position) in cases {
+        let error = Day08.parse(input).err().unwrap();
+        let error = error.downcast_ref::<ParseError>().unwrap();
+        assert_eq!((error.line(), error.column()), position, "{}", input);
+    }
+}
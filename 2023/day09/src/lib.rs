@@ -0,0 +1,156 @@
+use anyhow::{anyhow, ensure, Result};
+use aoc::{parse, Answer, Solution};
+
+fn overflow() -> anyhow::Error {
+    anyhow!("polynomial value overflows")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    differences: Vec<i128>,
+}
+
+impl Polynomial {
+    pub fn fit(values: &[i128]) -> Result<Self> {
+        ensure!(!values.is_empty(), "history is empty");
+        let mut row = values.to_vec();
+        let mut differences = vec![];
+        while row.iter().any(|&value| value != 0) {
+            ensure!(
+                row.len() > 1,
+                "history of {} values never reaches a zero difference row",
+                values.len()
+            );
+            differences.push(row[0]);
+            row = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]).ok_or_else(overflow))
+                .collect::<Result<_>>()?;
+        }
+        Ok(Self { differences })
+    }
+    pub fn degree(&self) -> usize {
+        self.differences.len().saturating_sub(1)
+    }
+    pub fn at(&self, x: i128) -> Result<i128> {
+        let mut binomial = 1i128;
+        let mut value = 0i128;
+        for (k, &difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                let k = k as i128;
+                binomial = binomial.checked_mul(x - k + 1).ok_or_else(overflow)? / k;
+            }
+            let term = difference.checked_mul(binomial).ok_or_else(overflow)?;
+            value = value.checked_add(term).ok_or_else(overflow)?;
+        }
+        Ok(value)
+    }
+}
+
+#[derive(Clone)]
+pub struct Sequence {
+    numbers: Vec<i128>,
+    polynomial: Polynomial,
+}
+
+impl Sequence {
+    pub fn new(numbers: Vec<i128>) -> Result<Self> {
+        let polynomial = Polynomial::fit(&numbers)?;
+        Ok(Self {
+            numbers,
+            polynomial,
+        })
+    }
+    pub fn degree(&self) -> usize {
+        self.polynomial.degree()
+    }
+    pub fn after(&self, steps: u64) -> Result<i128> {
+        let last = self.numbers.len() as i128 - 1;
+        self.polynomial.at(last + steps as i128)
+    }
+    pub fn before(&self, steps: u64) -> Result<i128> {
+        self.polynomial.at(-(steps as i128))
+    }
+}
+
+pub struct Sequences {
+    sequences: Vec<Sequence>,
+}
+
+impl Sequences {
+    fn new(sequences: Vec<Sequence>) -> Self {
+        Self { sequences }
+    }
+    fn from_str(content: &str) -> Result<Self> {
+        let sequences_vec =
+            parse::each_line(content, |line| Sequence::new(parse::numbers(line.text())?))?;
+        Ok(Sequences::new(sequences_vec))
+    }
+    fn sum(&self, extrapolate: impl Fn(&Sequence) -> Result<i128>) -> Result<i128> {
+        self.sequences.iter().try_fold(0i128, |acc, sequence| {
+            acc.checked_add(extrapolate(sequence)?)
+                .ok_or_else(|| anyhow!("sum of extrapolated values overflows"))
+        })
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Sequences;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Sequences::from_str(input)
+    }
+    fn part1(&self, sequences: &Self::Input) -> Result<Answer> {
+        Ok(sequences.sum(|sequence| sequence.after(1))?.into())
+    }
+    fn part2(&self, sequences: &Self::Input) -> Result<Answer> {
+        Ok(sequences.sum(|sequence| sequence.before(1))?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extrapolates_in_both_directions() {
+        let sequence = Sequence::new(vec![10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(sequence.degree(), 3);
+        assert_eq!(sequence.after(1).unwrap(), 68);
+        assert_eq!(sequence.before(1).unwrap(), 5);
+        let squares = Sequence::new(vec![1, 4, 9, 16]).unwrap();
+        assert_eq!(squares.after(6).unwrap(), 100);
+        assert_eq!(squares.before(3).unwrap(), 4);
+    }
+
+    #[test]
+    fn constant_and_zero_histories() {
+        let constant = Sequence::new(vec![7, 7]).unwrap();
+        assert_eq!((constant.degree(), constant.after(5).unwrap()), (0, 7));
+        let zeros = Sequence::new(vec![0, 0]).unwrap();
+        assert_eq!((zeros.degree(), zeros.before(2).unwrap()), (0, 0));
+    }
+
+    #[test]
+    fn steep_histories_stay_exact() {
+        let steep = (0..12).map(|x: i128| 1_000_000 * x.pow(9)).collect();
+        let sequence = Sequence::new(steep).unwrap();
+        assert_eq!(sequence.degree(), 9);
+        assert_eq!(sequence.after(1).unwrap(), 1_000_000 * 12i128.pow(9));
+        assert_eq!(sequence.before(2).unwrap(), -1_000_000 * 2i128.pow(9));
+    }
+
+    #[test]
+    fn errors_without_a_zero_row() {
+        let error = Sequence::new(vec![1, 2, 4, 8]).err().unwrap();
+        assert!(error
+            .to_string()
+            .contains("never reaches a zero difference row"));
+        assert!(Sequence::new(vec![7]).is_err());
+        assert!(Sequence::new(vec![]).is_err());
+        let huge = Sequence::new(vec![0, i128::MAX / 2, i128::MAX / 2 * 2]).unwrap();
+        assert!(huge.after(10).is_err());
+    }
+}
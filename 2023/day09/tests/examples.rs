@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2023_day09::Day09;
+
+#[test]
+fn example() {
+    assert_parts(&Day09, include_str!("../input2.txt"), 114, 2);
+}
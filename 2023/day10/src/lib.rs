@@ -0,0 +1,266 @@
+use std::{
+    collections::{hash_map::RandomState, HashSet},
+    fmt::{Display, Write},
+    vec,
+};
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
+
+use anyhow::{anyhow, bail, ensure, Error, Result};
+use aoc::{
+    geometry::{Direction, Point},
+    grid::Grid,
+    search, Answer, Solution,
+};
+
+#[derive(Debug, EnumIter, PartialEq, Eq, Hash)]
+enum PipeType {
+    Ground,
+    Vertical,
+    Horizontal,
+    DownLeft,
+    DownRight,
+    UpLeft,
+    UpRight,
+    Start,
+}
+
+impl TryFrom<char> for PipeType {
+    type Error = Error;
+
+    fn try_from(character: char) -> Result<Self, Self::Error> {
+        Ok(match character {
+            '.' => Self::Ground,
+            '|' => Self::Vertical,
+            '-' => Self::Horizontal,
+            'J' => Self::UpLeft,
+            'L' => Self::UpRight,
+            '7' => Self::DownLeft,
+            'F' => Self::DownRight,
+            'S' => Self::Start,
+            _ => return Err(anyhow!("no pipe type for {:?}", character)),
+        })
+    }
+}
+
+impl PipeType {
+    fn get_directions(&self) -> Vec<Direction> {
+        match self {
+            PipeType::Vertical => vec![Direction::Up, Direction::Down],
+            PipeType::Horizontal => vec![Direction::Left, Direction::Right],
+            PipeType::DownLeft => vec![Direction::Down, Direction::Left],
+            PipeType::DownRight => vec![Direction::Down, Direction::Right],
+            PipeType::UpLeft => vec![Direction::Up, Direction::Left],
+            PipeType::UpRight => vec![Direction::Up, Direction::Right],
+            PipeType::Start => Direction::ALL.to_vec(),
+            PipeType::Ground => vec![],
+        }
+    }
+    fn from_directions(directions: &[Direction]) -> Option<Self> {
+        Self::iter().find(|pipe_type| {
+            let pipe_type_directions = pipe_type.get_directions();
+            directions
+                .iter()
+                .all(|direction| pipe_type_directions.contains(direction))
+        })
+    }
+    fn to_char(&self) -> char {
+        match self {
+            Self::Ground => '.',
+            Self::Vertical => '|',
+            Self::Horizontal => '-',
+            Self::UpLeft => 'J',
+            Self::UpRight => 'L',
+            Self::DownLeft => '7',
+            Self::DownRight => 'F',
+            Self::Start => 'S',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Pipe {
+    pos: Point,
+    pipe_type: PipeType,
+}
+
+impl Pipe {
+    fn new(pos: Point, pipe_type: PipeType) -> Self {
+        Self { pos, pipe_type }
+    }
+    fn is_pipe_connected_neighbour(&self, pipe: &Self) -> bool {
+        self.pipe_type
+            .get_directions()
+            .into_iter()
+            .any(|direction| {
+                self.pos.step(direction) == pipe.pos
+                    && pipe
+                        .pipe_type
+                        .get_directions()
+                        .into_iter()
+                        .any(|neighbour_direction| pipe.pos.step(neighbour_direction) == self.pos)
+            })
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_char(self.pipe_type.to_char())
+    }
+}
+
+#[derive(Debug)]
+pub struct PipeMap {
+    pipe_map: Grid<Pipe>,
+    start_pipe_pos: (usize, usize),
+}
+
+impl PipeMap {
+    fn new(pipe_map: Grid<Pipe>, start_pipe_pos: (usize, usize)) -> Self {
+        Self {
+            pipe_map,
+            start_pipe_pos,
+        }
+    }
+
+    fn from_str(content: &str) -> Result<Self> {
+        let mut start_pipe_positions = vec![];
+        let pipe_map_vec = Grid::parse(content, |pos, character| {
+            let pipe_type = PipeType::try_from(character)?;
+            if pipe_type == PipeType::Start {
+                start_pipe_positions.push(pos);
+            }
+            Ok(Pipe::new(Point::from(pos), pipe_type))
+        })?;
+        let start_pipe_pos = match start_pipe_positions.as_slice() {
+            [start_pipe_pos] => *start_pipe_pos,
+            _ => bail!(
+                "expected 1 start pipe, found {}",
+                start_pipe_positions.len()
+            ),
+        };
+        PipeMap::new(pipe_map_vec, start_pipe_pos).without_start_pipe_type()
+    }
+    fn get_start_pipe(&self) -> &Pipe {
+        &self.pipe_map[self.start_pipe_pos]
+    }
+    fn get_start_pipe_mut(&mut self) -> &mut Pipe {
+        &mut self.pipe_map[self.start_pipe_pos]
+    }
+    fn without_start_pipe_type(mut self) -> Result<Self> {
+        let start_pipe = self.get_start_pipe();
+        let new_directions = Direction::ALL
+            .into_iter()
+            .filter(|&direction| {
+                let neighbour_pipe_option = self.get_pipe_at_pos(start_pipe.pos.step(direction));
+                let neighbour_pipe = match neighbour_pipe_option {
+                    Some(neighbour_pipe_some) => neighbour_pipe_some,
+                    None => return false,
+                };
+                neighbour_pipe
+                    .pipe_type
+                    .get_directions()
+                    .into_iter()
+                    .any(|neighbour_direction| neighbour_direction.opposite() == direction)
+            })
+            .collect::<Vec<_>>();
+        ensure!(
+            new_directions.len() == 2,
+            "start pipe at {} connects to {} pipes, expected 2",
+            start_pipe.pos,
+            new_directions.len()
+        );
+        let new_pipe_type = PipeType::from_directions(&new_directions)
+            .ok_or_else(|| anyhow!("no pipe type connects {:?}", new_directions))?;
+        self.get_start_pipe_mut().pipe_type = new_pipe_type;
+        Ok(self)
+    }
+    fn get_pipe_at_pos(&self, pos: Point) -> Option<&Pipe> {
+        self.pipe_map.get_signed(pos)
+    }
+    fn get_pipe_neighbour(&self, pipe: &Pipe, direction: Direction) -> Option<&Pipe> {
+        self.get_pipe_at_pos(pipe.pos.step(direction))
+    }
+    fn get_connected_pipe_neighbours(&self, pipe: &Pipe) -> Vec<&Pipe> {
+        pipe.pipe_type
+            .get_directions()
+            .into_iter()
+            .filter_map(|direction| {
+                let pipe_neighbour = self.get_pipe_neighbour(pipe, direction)?;
+                if pipe.is_pipe_connected_neighbour(pipe_neighbour) {
+                    Some(pipe_neighbour)
+                } else {
+                    None
+                }
+            })
+            .collect::<Vec<_>>()
+    }
+    fn walk_loop(&self) -> search::Search<&Pipe, usize> {
+        search::bfs(
+            [self.get_start_pipe()],
+            |pipe| self.get_connected_pipe_neighbours(pipe),
+            |_| false,
+        )
+    }
+    fn get_pipe_furthest_away_count(&self) -> usize {
+        self.walk_loop()
+            .reached()
+            .map(|(_, distance)| distance)
+            .max()
+            .unwrap_or(0)
+    }
+    fn get_pipes_in_loop(&self) -> Vec<&Pipe> {
+        self.walk_loop()
+            .reached()
+            .map(|(&pipe, _)| pipe)
+            .collect::<Vec<_>>()
+    }
+    fn find_enclosed_pipes(&self) -> Vec<&Pipe> {
+        let pipes_in_loop = self.get_pipes_in_loop();
+        let pipes_in_loop_hash: HashSet<&Pipe, RandomState> =
+            HashSet::from_iter(pipes_in_loop.iter().cloned());
+        let mut enclosed_pipes = vec![];
+        let inner_row_count = self.pipe_map.height().saturating_sub(2);
+        for row in self.pipe_map.rows().skip(1).take(inner_row_count) {
+            let mut is_inside = false;
+            for pipe in &row[..(row.len() - 1)] {
+                if pipes_in_loop_hash.contains(pipe) {
+                    if pipe.pipe_type == PipeType::Vertical
+                        || pipe.pipe_type == PipeType::DownLeft
+                        || pipe.pipe_type == PipeType::DownRight
+                    {
+                        is_inside = !is_inside
+                    }
+                } else if is_inside {
+                    enclosed_pipes.push(pipe)
+                }
+            }
+        }
+        enclosed_pipes
+    }
+    fn find_enclosed_pipes_count(&self) -> usize {
+        self.find_enclosed_pipes().len()
+    }
+}
+
+impl Display for PipeMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.pipe_map.fmt(f)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeMap;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        PipeMap::from_str(input)
+    }
+    fn part1(&self, pipe_map: &Self::Input) -> Result<Answer> {
+        Ok(pipe_map.get_pipe_furthest_away_count().into())
+    }
+    fn part2(&self, pipe_map: &Self::Input) -> Result<Answer> {
+        Ok(pipe_map.find_enclosed_pipes_count().into())
+    }
+}
@@ -0,0 +1,15 @@
+use aoc::{
+    testing::{assert_part, assert_parts},
+    Part,
+};
+use aoc2023_day10::Day10;
+
+#[test]
+fn example() {
+    assert_parts(&Day10, include_str!("../input2.txt"), 8, 1);
+}
+
+#[test]
+fn enclosed_tiles_example() {
+    assert_part(&Day10, include_str!("../input3.txt"), Part::Two, 10);
+}
@@ -0,0 +1,124 @@
+use anyhow::{anyhow, Result};
+use aoc::{geometry::Point, grid::Grid, Answer, Solution};
+use itertools::Itertools;
+
+struct Galaxy {
+    coordinate: Point,
+}
+
+impl Galaxy {
+    fn new(coordinate: Point) -> Self {
+        Self { coordinate }
+    }
+    fn calculate_length_to_galaxy(
+        &self,
+        galaxy: &Self,
+        expanded_columns: &[usize],
+        expanded_rows: &[usize],
+        expand_size: u64,
+    ) -> u64 {
+        let (a, b) = (self.coordinate, galaxy.coordinate);
+        let is_between = |index: usize, from: isize, to: isize| {
+            (from.min(to) + 1..from.max(to)).contains(&(index as isize))
+        };
+
+        let expanded_columns_count = expanded_columns
+            .iter()
+            .filter(|&&column_index| is_between(column_index, a.x, b.x))
+            .count();
+        let expanded_rows_count = expanded_rows
+            .iter()
+            .filter(|&&row_index| is_between(row_index, a.y, b.y))
+            .count();
+
+        let expanded_size =
+            (expanded_columns_count + expanded_rows_count) as u64 * (expand_size - 1);
+
+        a.manhattan(b) as u64 + expanded_size
+    }
+}
+
+pub struct Universe {
+    galaxies: Grid<Option<Galaxy>>,
+}
+
+impl Universe {
+    fn new(galaxies: Grid<Option<Galaxy>>) -> Self {
+        Self { galaxies }
+    }
+
+    fn from_str(content: &str) -> Result<Self> {
+        let galaxies = Grid::parse(content, |position, character| match character {
+            '#' => Ok(Some(Galaxy::new(Point::from(position)))),
+            '.' => Ok(None),
+            _ => Err(anyhow!("expected '#' or '.', found {:?}", character)),
+        })?;
+        Ok(Self::new(galaxies))
+    }
+
+    fn get_galaxies(&self) -> Vec<&Galaxy> {
+        self.galaxies.values().flatten().collect::<Vec<_>>()
+    }
+
+    fn get_expanded_columns(&self) -> Vec<usize> {
+        (0..self.galaxies.width())
+            .filter(|&x| self.galaxies.column(x).all(|galaxy| galaxy.is_none()))
+            .collect()
+    }
+
+    fn get_expanded_rows(&self) -> Vec<usize> {
+        (0..self.galaxies.height())
+            .filter(|&y| self.galaxies.row(y).all(|galaxy| galaxy.is_none()))
+            .collect()
+    }
+
+    fn get_all_galaxy_pairs(&self) -> Vec<(&Galaxy, &Galaxy)> {
+        let galaxies_vec = self.get_galaxies();
+        (0..(galaxies_vec.len()))
+            .flat_map(|i| {
+                (0..galaxies_vec.len())
+                    .filter_map(|j| {
+                        if i > j {
+                            Some((galaxies_vec[i], galaxies_vec[j]))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect_vec()
+            })
+            .collect_vec()
+    }
+
+    fn find_shortest_paths_sum(&self, expand_size: u64) -> u64 {
+        let galaxy_pairs = self.get_all_galaxy_pairs();
+        let expanded_columns = self.get_expanded_columns();
+        let expanded_rows = self.get_expanded_rows();
+        galaxy_pairs
+            .into_iter()
+            .fold(0, |acc, (galaxy_a, galaxy_b)| {
+                let galaxy_diff = galaxy_a.calculate_length_to_galaxy(
+                    galaxy_b,
+                    &expanded_columns,
+                    &expanded_rows,
+                    expand_size,
+                );
+                acc + galaxy_diff
+            })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Universe;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Universe::from_str(input)
+    }
+    fn part1(&self, universe: &Self::Input) -> Result<Answer> {
+        Ok(universe.find_shortest_paths_sum(2).into())
+    }
+    fn part2(&self, universe: &Self::Input) -> Result<Answer> {
+        Ok(universe.find_shortest_paths_sum(1_000_000).into())
+    }
+}
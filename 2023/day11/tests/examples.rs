@@ -0,0 +1,7 @@
+use aoc::testing::assert_parts;
+use aoc2023_day11::Day11;
+
+#[test]
+fn example() {
+    assert_parts(&Day11, include_str!("../input2.txt"), 374, 82000210);
+}
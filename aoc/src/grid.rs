@@ -0,0 +1,244 @@
+use crate::{geometry::Point, parse};
+use anyhow::{ensure, Result};
+use std::{
+    fmt::{Display, Write},
+    ops::{Index, IndexMut},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Self> {
+        ensure!(
+            cells.len() == width * height,
+            "grid of {}x{} needs {} cells, got {}",
+            width,
+            height,
+            width * height,
+            cells.len()
+        );
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            ensure!(
+                row.len() == width,
+                "row {} has {} cells, expected {}",
+                y,
+                row.len(),
+                width
+            );
+            cells.extend(row);
+        }
+        Self::new(width, height, cells)
+    }
+    pub fn parse(
+        string: &str,
+        mut func: impl FnMut((usize, usize), char) -> Result<T>,
+    ) -> Result<Self> {
+        let rows = parse::lines(string)
+            .enumerate()
+            .map(|(y, line)| {
+                line.text()
+                    .chars()
+                    .enumerate()
+                    .map(|(x, character)| {
+                        func((x, y), character).map_err(|error| {
+                            line.error_at_column(
+                                x + 1,
+                                format!(
+                                    "invalid character {:?} at ({}, {}): {:#}",
+                                    character, x, y, error
+                                ),
+                            )
+                            .into()
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::from_rows(rows)
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+    pub fn checked_position(&self, point: impl Into<Point>) -> Option<(usize, usize)> {
+        let position = point.into().try_into().ok()?;
+        self.contains(position).then_some(position)
+    }
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[position.1 * self.width + position.0])
+    }
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.contains(position)
+            .then(|| &mut self.cells[position.1 * self.width + position.0])
+    }
+    pub fn get_signed(&self, point: impl Into<Point>) -> Option<&T> {
+        self.get(self.checked_position(point)?)
+    }
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter()
+    }
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, value)| predicate(value))
+            .map(|(position, _)| position)
+    }
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+    pub fn neighbors(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Point::from(position)
+            .neighbors()
+            .filter_map(|point| self.checked_position(point))
+    }
+    pub fn all_neighbors(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Point::from(position)
+            .all_neighbors()
+            .filter_map(|point| self.checked_position(point))
+    }
+    pub fn map<U>(&self, func: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(func).collect(),
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("position {:?} out of bounds", position))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                f.write_char('\n')?;
+            }
+            for value in row {
+                value.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    fn digits() -> Grid<u32> {
+        Grid::parse("123\n456", |_, character| {
+            character.to_digit(10).ok_or(anyhow!("not a digit"))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn parse_passes_positions() {
+        let grid = Grid::parse("ab\ncd", |pos, character| Ok((pos, character))).unwrap();
+        assert_eq!(grid[(0, 1)], ((0, 1), 'c'));
+        assert_eq!(grid[(1, 0)], ((1, 0), 'b'));
+    }
+
+    #[test]
+    fn parse_reports_position() {
+        let err = Grid::parse("12\n3x", |_, character| {
+            character.to_digit(10).ok_or(anyhow!("not a digit"))
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("(1, 1)"));
+        let err = err.downcast::<parse::ParseError>().unwrap();
+        assert_eq!((err.line(), err.column()), (2, 2));
+    }
+
+    #[test]
+    fn parse_rejects_ragged_rows() {
+        let err = Grid::parse("ab\nc", |_, character| Ok(character)).unwrap_err();
+        assert_eq!(err.to_string(), "row 1 has 1 cells, expected 2");
+    }
+
+    #[test]
+    fn checked_indexing() {
+        let grid = digits();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&6));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get_signed(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_signed(Point::new(0, 1)), Some(&4));
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = digits();
+        assert_eq!(grid.row(1).copied().collect::<Vec<_>>(), [4, 5, 6]);
+        assert_eq!(grid.column(2).rev().copied().collect::<Vec<_>>(), [6, 3]);
+        assert_eq!(grid.rows().count(), 2);
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = digits();
+        let mut neighbors = grid.neighbors((0, 0)).collect::<Vec<_>>();
+        neighbors.sort();
+        assert_eq!(neighbors, [(0, 1), (1, 0)]);
+        assert_eq!(grid.all_neighbors((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn display_and_map() {
+        let grid = digits().map(|digit| digit * 2);
+        assert_eq!(grid.to_string(), "246\n81012");
+        assert_eq!(grid.position(|&value| value == 10), Some((1, 1)));
+    }
+}
@@ -0,0 +1,18 @@
+use anyhow::{Context, Result};
+use std::{fs::read_to_string, path::Path};
+
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    read_to_string(path).with_context(|| format!("cannot read input file {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_file_names_path() {
+        let err = read_input("does/not/exist.txt").unwrap_err();
+        assert!(err.to_string().contains("does/not/exist.txt"));
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    mem,
+    ops::{Bound, RangeBounds, RangeInclusive},
+};
+
+pub trait Endpoint: Copy + Ord {
+    const MIN: Self;
+    const MAX: Self;
+
+    fn successor(self) -> Option<Self>;
+    fn predecessor(self) -> Option<Self>;
+}
+
+macro_rules! impl_endpoint {
+    ($($type:ty),*) => {
+        $(
+            impl Endpoint for $type {
+                const MIN: Self = <$type>::MIN;
+                const MAX: Self = <$type>::MAX;
+
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+fn inclusive_bounds<T: Endpoint>(range: &impl RangeBounds<T>) -> Option<(T, T)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.successor()?,
+        Bound::Unbounded => T::MIN,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.predecessor()?,
+        Bound::Unbounded => T::MAX,
+    };
+    (start <= end).then_some((start, end))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<(T, T)>,
+}
+
+impl<T: Endpoint> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self { intervals: vec![] }
+    }
+    pub fn from_range(range: impl RangeBounds<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(range);
+        set
+    }
+    fn from_sorted(sorted: impl IntoIterator<Item = (T, T)>) -> Self {
+        let mut intervals: Vec<(T, T)> = vec![];
+        for (start, end) in sorted {
+            match intervals.last_mut() {
+                Some((_, last_end)) if last_end.successor().is_none_or(|next| start <= next) => {
+                    *last_end = end.max(*last_end);
+                }
+                _ => intervals.push((start, end)),
+            }
+        }
+        Self { intervals }
+    }
+    pub fn insert(&mut self, range: impl RangeBounds<T>) {
+        if let Some(interval) = inclusive_bounds(&range) {
+            let index = self.intervals.partition_point(|&other| other < interval);
+            self.intervals.insert(index, interval);
+            *self = Self::from_sorted(mem::take(&mut self.intervals));
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|&(start, end)| start..=end)
+    }
+    pub fn min(&self) -> Option<T> {
+        self.intervals.first().map(|&(start, _)| start)
+    }
+    pub fn max(&self) -> Option<T> {
+        self.intervals.last().map(|&(_, end)| end)
+    }
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|&(_, end)| end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|&(start, _)| start <= value)
+    }
+    pub fn contains_range(&self, range: impl RangeBounds<T>) -> bool {
+        self.is_superset(&Self::from_range(range))
+    }
+    pub fn overlaps_range(&self, range: impl RangeBounds<T>) -> bool {
+        !self.is_disjoint(&Self::from_range(range))
+    }
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.difference(self).is_empty()
+    }
+    pub fn is_subset(&self, other: &Self) -> bool {
+        other.is_superset(self)
+    }
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+    pub fn complement(&self) -> Self {
+        let mut intervals = vec![];
+        let mut start = Some(T::MIN);
+        for &(interval_start, interval_end) in &self.intervals {
+            if let Some(gap_start) = start.filter(|&gap_start| gap_start < interval_start) {
+                intervals.push((gap_start, interval_start.predecessor().unwrap()));
+            }
+            start = interval_end.successor();
+        }
+        if let Some(gap_start) = start {
+            intervals.push((gap_start, T::MAX));
+        }
+        Self { intervals }
+    }
+    pub fn union(&self, other: &Self) -> Self {
+        let mut intervals = [self.intervals.as_slice(), other.intervals.as_slice()].concat();
+        intervals.sort();
+        Self::from_sorted(intervals)
+    }
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = vec![];
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&(a_start, a_end)), Some(&(b_start, b_end))) =
+            (self.intervals.get(i), other.intervals.get(j))
+        {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                intervals.push((start, end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+}
+
+impl<T: Endpoint, R: RangeBounds<T>> FromIterator<R> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = R>>(ranges: I) -> Self {
+        let mut intervals = ranges
+            .into_iter()
+            .filter_map(|range| inclusive_bounds(&range))
+            .collect::<Vec<_>>();
+        intervals.sort();
+        Self::from_sorted(intervals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges<T: Endpoint>(set: &IntervalSet<T>) -> Vec<RangeInclusive<T>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_ranges() {
+        let set = [5..8, 1..3, 3..4, 10..10, 7..9]
+            .into_iter()
+            .collect::<IntervalSet<u32>>();
+        assert_eq!(ranges(&set), vec![1..=3, 5..=8]);
+        let mut set = IntervalSet::from_range(0..=u8::MAX);
+        set.insert(3..=4);
+        assert_eq!(ranges(&set), vec![0..=u8::MAX]);
+    }
+
+    #[test]
+    fn half_open_and_inclusive_bounds_agree() {
+        assert_eq!(
+            IntervalSet::from_range(2..7),
+            IntervalSet::from_range(2..=6)
+        );
+        assert!(IntervalSet::from_range(4..4).is_empty());
+        assert_eq!(ranges(&IntervalSet::from_range(250u8..)), vec![250..=255]);
+        assert_eq!(
+            ranges(&IntervalSet::<i8>::from_range(..-126)),
+            vec![-128..=-127]
+        );
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = [0..=10, 20..=30].into_iter().collect::<IntervalSet<i32>>();
+        let b = [5..=25].into_iter().collect::<IntervalSet<i32>>();
+        assert_eq!(ranges(&a.union(&b)), vec![0..=30]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..=10, 20..=25]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..=4, 26..=30]);
+        assert_eq!(ranges(&b.difference(&a)), vec![11..=19]);
+        assert_eq!(
+            ranges(&a.complement()),
+            vec![i32::MIN..=-1, 11..=19, 31..=i32::MAX]
+        );
+        assert!(IntervalSet::<u8>::new().complement().contains(0));
+        assert!(IntervalSet::<u16>::from_range(..).complement().is_empty());
+    }
+
+    #[test]
+    fn containment_and_overlap() {
+        let set = [2..=4, 6..=8].into_iter().collect::<IntervalSet<u32>>();
+        assert!(set.contains(2) && set.contains(8));
+        assert!(!set.contains(5) && !set.contains(9));
+        assert!(set.contains_range(6..9));
+        assert!(!set.contains_range(3..=6));
+        assert!(set.overlaps_range(8..20));
+        assert!(!set.overlaps_range(9..20));
+        assert!(!set.overlaps_range(5..6));
+        let inner = IntervalSet::from_range(7..=7);
+        assert!(inner.is_subset(&set) && set.is_superset(&inner));
+        assert!(!set.is_subset(&inner));
+        assert_eq!((set.min(), set.max()), (Some(2), Some(8)));
+    }
+}
@@ -0,0 +1,11 @@
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod testing;
+
+pub use solution::{Answer, DynSolution, Part, PartResult, Run, Solution};
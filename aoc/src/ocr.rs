@@ -0,0 +1,112 @@
+use crate::grid::Grid;
+use anyhow::{bail, ensure, Result};
+use std::collections::HashMap;
+
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+const GLYPH_STRIDE: usize = GLYPH_WIDTH + 1;
+
+const LETTERS: &str = "ABCEFGHIJKLOPRSUZ";
+const FONT: &str = "\
+.##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.####.
+#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#....#.
+#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#...#..
+####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#..#...
+#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#.#....
+#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##..####.";
+
+fn glyph_count(pixels: &Grid<bool>) -> usize {
+    pixels.width().div_ceil(GLYPH_STRIDE)
+}
+
+fn glyph_at(pixels: &Grid<bool>, index: usize) -> u32 {
+    let left = index * GLYPH_STRIDE;
+    let mut glyph = 0;
+    for y in 0..GLYPH_HEIGHT {
+        for x in left..left + GLYPH_WIDTH {
+            let lit = pixels.get((x, y)).copied().unwrap_or(false);
+            glyph = glyph << 1 | lit as u32;
+        }
+    }
+    glyph
+}
+
+fn font() -> HashMap<u32, char> {
+    let pixels = Grid::parse(FONT, |_, character| Ok(character == '#')).expect("font is valid");
+    LETTERS
+        .chars()
+        .enumerate()
+        .map(|(index, letter)| (glyph_at(&pixels, index), letter))
+        .collect()
+}
+
+pub fn read_letters(pixels: &Grid<bool>) -> Result<String> {
+    ensure!(
+        pixels.height() == GLYPH_HEIGHT,
+        "letters are {} pixels high, got {} rows",
+        GLYPH_HEIGHT,
+        pixels.height()
+    );
+    let font = font();
+    let mut unrecognized = vec![];
+    let letters = (0..glyph_count(pixels))
+        .filter_map(|index| {
+            let letter = font.get(&glyph_at(pixels, index)).copied();
+            if letter.is_none() {
+                unrecognized.push(format!("{} (x = {})", index, index * GLYPH_STRIDE));
+            }
+            letter
+        })
+        .collect::<String>();
+    if !unrecognized.is_empty() {
+        bail!("unrecognized glyphs at {}", unrecognized.join(", "));
+    }
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pixels(art: &str) -> Grid<bool> {
+        Grid::parse(art, |_, character| Ok(character == '#')).unwrap()
+    }
+
+    #[test]
+    fn reads_whole_font() {
+        assert_eq!(read_letters(&pixels(FONT)).unwrap(), LETTERS);
+    }
+
+    #[test]
+    fn reads_without_trailing_spacer() {
+        let art = "\
+#..#..###.
+#..#...#..
+####...#..
+#..#...#..
+#..#...#..
+#..#..###.";
+        assert_eq!(read_letters(&pixels(art)).unwrap(), "HI");
+        let trimmed = art.lines().map(|line| &line[..9]).collect::<Vec<_>>();
+        assert_eq!(read_letters(&pixels(&trimmed.join("\n"))).unwrap(), "HI");
+    }
+
+    #[test]
+    fn lists_unrecognized_glyphs() {
+        let art = "\
+#..#.####.####.
+#..#.####.#....
+####.####.###..
+#..#.####.#....
+#..#.####.#....
+#..#.####.####.";
+        let error = read_letters(&pixels(art)).unwrap_err();
+        assert_eq!(error.to_string(), "unrecognized glyphs at 1 (x = 5)");
+    }
+
+    #[test]
+    fn rejects_wrong_height() {
+        let error = read_letters(&pixels("#..#\n####")).unwrap_err();
+        assert!(error.to_string().contains("got 2 rows"));
+    }
+}
@@ -0,0 +1,301 @@
+use anyhow::{anyhow, Result};
+use std::{error::Error, fmt::Display, mem, str::FromStr};
+
+pub fn numbers<T>(string: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    string
+        .split_whitespace()
+        .map(|numstr| {
+            numstr
+                .parse::<T>()
+                .map_err(|err| anyhow!("invalid number {:?}: {}", numstr, err))
+        })
+        .collect()
+}
+
+pub fn blocks(string: &str) -> impl Iterator<Item = &str> {
+    string
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    snippet: String,
+    message: String,
+}
+
+impl ParseError {
+    pub fn line(&self) -> usize {
+        self.line
+    }
+    pub fn column(&self) -> usize {
+        self.column
+    }
+    pub fn snippet(&self) -> &str {
+        &self.snippet
+    }
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}\n    {}\n    {:>width$}",
+            self.line,
+            self.column,
+            self.message,
+            self.snippet,
+            "^",
+            width = self.column
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+pub fn line_blocks(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut blocks = vec![];
+    let mut block = vec![];
+    for line in lines(input) {
+        if !line.text.trim().is_empty() {
+            block.push(line);
+        } else if !block.is_empty() {
+            blocks.push(mem::take(&mut block));
+        }
+    }
+    if !block.is_empty() {
+        blocks.push(block);
+    }
+    blocks
+}
+
+pub fn each_line<T>(input: &str, mut func: impl FnMut(Line) -> Result<T>) -> Result<Vec<T>> {
+    lines(input)
+        .map(|line| {
+            func(line).map_err(|error| {
+                if error.is::<ParseError>() {
+                    error
+                } else {
+                    line.error_at_column(1, format!("{:#}", error)).into()
+                }
+            })
+        })
+        .collect()
+}
+
+impl<'a> Line<'a> {
+    pub fn number(&self) -> usize {
+        self.number
+    }
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+    pub fn error_at_column(&self, column: usize, message: impl Display) -> ParseError {
+        ParseError {
+            line: self.number,
+            column,
+            snippet: self.text.to_string(),
+            message: message.to_string(),
+        }
+    }
+    pub fn error(&self, part: &str, message: impl Display) -> ParseError {
+        self.error_at_column(self.column_of(part), message)
+    }
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset.saturating_add(part.len()) > self.text.len() {
+            return 1;
+        }
+        self.text[..offset].chars().count() + 1
+    }
+    pub fn parse<T>(&self, part: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        part.parse().map_err(|err| {
+            self.error(part, format!("cannot parse {:?}: {}", part, err))
+                .into()
+        })
+    }
+    pub fn numbers<T>(&self, part: &str) -> Result<Vec<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let bytes = part.as_bytes();
+        let mut numbers = vec![];
+        let mut index = 0;
+        while index < bytes.len() {
+            let follows_digit = index > 0 && bytes[index - 1].is_ascii_digit();
+            let signed = bytes[index] == b'-'
+                && !follows_digit
+                && bytes.get(index + 1).is_some_and(u8::is_ascii_digit);
+            if !signed && !bytes[index].is_ascii_digit() {
+                index += 1;
+                continue;
+            }
+            let start = index;
+            index += 1;
+            while index < bytes.len() && bytes[index].is_ascii_digit() {
+                index += 1;
+            }
+            numbers.push(self.parse(&part[start..index])?);
+        }
+        Ok(numbers)
+    }
+    pub fn value_of(&self, key: &str) -> Result<&'a str> {
+        let text = self.text.trim_start();
+        text.strip_prefix(key)
+            .and_then(|rest| rest.strip_prefix(':'))
+            .map(str::trim)
+            .ok_or_else(|| self.error(text, format!("expected \"{}:\"", key)).into())
+    }
+    pub fn scan<const N: usize>(&self, pattern: &str) -> Result<[&'a str; N]> {
+        let literals = pattern.split("{}").collect::<Vec<_>>();
+        if literals.len() != N + 1 {
+            return Err(anyhow!(
+                "pattern {:?} has {} fields, expected {}",
+                pattern,
+                literals.len() - 1,
+                N
+            ));
+        }
+        let text = self.text.trim_start();
+        let mut rest = text
+            .strip_prefix(literals[0])
+            .ok_or_else(|| self.error(text, format!("expected {:?}", literals[0])))?;
+        let mut fields = [""; N];
+        for (field, &literal) in fields.iter_mut().zip(&literals[1..]) {
+            let (value, after) = if literal.is_empty() {
+                (rest, &rest[rest.len()..])
+            } else {
+                let index = rest
+                    .find(literal)
+                    .ok_or_else(|| self.error(rest, format!("expected {:?}", literal)))?;
+                (&rest[..index], &rest[index + literal.len()..])
+            };
+            *field = value.trim();
+            rest = after;
+        }
+        if !rest.trim().is_empty() {
+            return Err(self.error(rest, "unexpected trailing text").into());
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_error(error: anyhow::Error) -> ParseError {
+        error.downcast::<ParseError>().unwrap()
+    }
+
+    #[test]
+    fn numbers_splits_on_whitespace() {
+        assert_eq!(numbers::<i32>(" 1 -2\t3 ").unwrap(), vec![1, -2, 3]);
+    }
+
+    #[test]
+    fn numbers_rejects_garbage() {
+        assert!(numbers::<u32>("1 x 3").is_err());
+    }
+
+    #[test]
+    fn blocks_skips_blank_lines() {
+        let blocks = blocks("a\nb\n\nc\n\n\nd\n").collect::<Vec<_>>();
+        assert_eq!(blocks, vec!["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn line_blocks_keep_line_numbers() {
+        let blocks = line_blocks("\na\nb\n\n\nc\n");
+        let numbers = blocks
+            .iter()
+            .map(|block| block.iter().map(Line::number).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(numbers, vec![vec![2, 3], vec![6]]);
+    }
+
+    #[test]
+    fn extracts_numbers_from_text() {
+        let line = lines("move 13 from -2 to 3-400").next().unwrap();
+        assert_eq!(
+            line.numbers::<i32>(line.text()).unwrap(),
+            vec![13, -2, 3, 400]
+        );
+        let error = parse_error(line.numbers::<i8>(line.text()).unwrap_err());
+        assert_eq!(error.column(), 22);
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_part() {
+        let line = lines("first\nmove x from 1").nth(1).unwrap();
+        let error = parse_error(line.parse::<u32>(&line.text()[5..6]).unwrap_err());
+        assert_eq!((error.line(), error.column()), (2, 6));
+        assert_eq!(error.snippet(), "move x from 1");
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 6: cannot parse \"x\": invalid digit found in string\n    \
+             move x from 1\n         ^"
+        );
+    }
+
+    #[test]
+    fn value_of_reads_key_value_lines() {
+        let line = lines("  Test: divisible by 23").next().unwrap();
+        assert_eq!(line.value_of("Test").unwrap(), "divisible by 23");
+        let error = parse_error(line.value_of("Operation").unwrap_err());
+        assert_eq!(error.column(), 3);
+    }
+
+    #[test]
+    fn scan_splits_on_literals() {
+        let line = lines("Card   1: 41 48 |  83 86").next().unwrap();
+        let [id, winning, numbers] = line.scan("Card {}: {} | {}").unwrap();
+        assert_eq!((id, winning, numbers), ("1", "41 48", "83 86"));
+
+        let line = lines("move 1 from 2 onto 3").next().unwrap();
+        let error = parse_error(line.scan::<3>("move {} from {} to {}").unwrap_err());
+        assert_eq!(error.column(), 13);
+        assert_eq!(error.message(), "expected \" to \"");
+        let line = lines("Monkey 0: extra").next().unwrap();
+        let error = parse_error(line.scan::<1>("Monkey {}:").unwrap_err());
+        assert_eq!(error.message(), "unexpected trailing text");
+    }
+
+    #[test]
+    fn each_line_locates_plain_errors() {
+        let error = each_line("1\n2\nx", |line| {
+            line.text().parse::<u32>().map_err(Into::into)
+        })
+        .unwrap_err();
+        assert_eq!(parse_error(error).line(), 3);
+    }
+}
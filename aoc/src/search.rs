@@ -0,0 +1,251 @@
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+#[derive(Debug, Clone)]
+pub struct Search<N, C> {
+    costs: HashMap<N, C>,
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> Search<N, C> {
+    fn new() -> Self {
+        Self {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+        }
+    }
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+    pub fn goal_cost(&self) -> Option<C> {
+        self.cost(self.goal.as_ref()?)
+    }
+    pub fn cost(&self, node: &N) -> Option<C> {
+        self.costs.get(node).copied()
+    }
+    pub fn reached(&self) -> impl Iterator<Item = (&N, C)> {
+        self.costs.iter().map(|(node, &cost)| (node, cost))
+    }
+    pub fn path(&self, node: &N) -> Option<Vec<N>> {
+        self.costs.get(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(parent) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+    pub fn goal_path(&self) -> Option<Vec<N>> {
+        self.path(self.goal.as_ref()?)
+    }
+}
+
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = search.costs.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        let cost = search.costs[&node] + 1;
+        for neighbor in neighbors(&node) {
+            if let Entry::Vacant(entry) = search.costs.entry(neighbor.clone()) {
+                entry.insert(cost);
+                search.parents.insert(neighbor.clone(), node.clone());
+                queue.push_back(neighbor);
+            }
+        }
+    }
+    search
+}
+
+struct Queued<N, C> {
+    priority: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Queued<N, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N, C: Ord> Eq for Queued<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Queued<N, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Queued<N, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut search = Search::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        search.costs.insert(start.clone(), C::default());
+        queue.push(Queued {
+            priority: heuristic(&start),
+            cost: C::default(),
+            node: start,
+        });
+    }
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        if search.costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+        for (neighbor, step_cost) in neighbors(&node) {
+            let neighbor_cost = cost + step_cost;
+            if search
+                .costs
+                .get(&neighbor)
+                .is_some_and(|&best| best <= neighbor_cost)
+            {
+                continue;
+            }
+            search.costs.insert(neighbor.clone(), neighbor_cost);
+            search.parents.insert(neighbor.clone(), node.clone());
+            queue.push(Queued {
+                priority: neighbor_cost + heuristic(&neighbor),
+                cost: neighbor_cost,
+                node: neighbor,
+            });
+        }
+    }
+    search
+}
+
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Search<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbors, |_| C::default(), is_goal)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Point;
+
+    const MAZE: &[&str] = &["S.#.", "..#E", "...."];
+
+    fn open(point: &Point) -> bool {
+        MAZE.get(point.y as usize)
+            .and_then(|row| row.as_bytes().get(point.x as usize))
+            .is_some_and(|&cell| cell != b'#')
+    }
+
+    fn maze_neighbors(point: &Point) -> Vec<Point> {
+        point.neighbors().filter(open).collect()
+    }
+
+    #[test]
+    fn bfs_finds_shortest_path() {
+        let end = Point::new(3, 1);
+        let search = bfs([Point::ORIGIN], maze_neighbors, |&point| point == end);
+        assert_eq!(search.goal_cost(), Some(6));
+        let path = search.goal_path().unwrap();
+        assert_eq!(path.len(), 7);
+        assert_eq!(path.first(), Some(&Point::ORIGIN));
+        assert!(path.windows(2).all(|pair| pair[0].manhattan(pair[1]) == 1));
+    }
+
+    #[test]
+    fn bfs_from_many_starts_takes_nearest() {
+        let end = Point::new(3, 1);
+        let starts = [Point::ORIGIN, Point::new(3, 0)];
+        let search = bfs(starts, maze_neighbors, |&point| point == end);
+        assert_eq!(search.goal_cost(), Some(1));
+        assert_eq!(search.goal_path().unwrap()[0], Point::new(3, 0));
+    }
+
+    #[test]
+    fn bfs_without_goal_floods_everything() {
+        let search = bfs([Point::ORIGIN], maze_neighbors, |_| false);
+        assert_eq!(search.goal(), None);
+        assert_eq!(search.reached().count(), 10);
+        assert_eq!(search.reached().map(|(_, cost)| cost).max(), Some(7));
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_detour() {
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 10), ('c', 1)],
+            'c' => vec![('d', 1)],
+            'd' => vec![('b', 1)],
+            _ => vec![],
+        };
+        let search = dijkstra(['a'], edges, |&node| node == 'b');
+        assert_eq!(search.goal_cost(), Some(3u32));
+        assert_eq!(search.goal_path().unwrap(), ['a', 'c', 'd', 'b']);
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let end = Point::new(3, 1);
+        let weighted = |point: &Point| {
+            maze_neighbors(point)
+                .into_iter()
+                .map(|neighbor| (neighbor, 1))
+                .collect::<Vec<_>>()
+        };
+        let search = astar(
+            [Point::ORIGIN],
+            weighted,
+            |point| point.manhattan(end),
+            |&point| point == end,
+        );
+        assert_eq!(search.goal_cost(), Some(6));
+        let unreachable = dijkstra([Point::ORIGIN], weighted, |&point| {
+            point == Point::new(9, 9)
+        });
+        assert_eq!(unreachable.goal_cost(), None);
+    }
+}
@@ -0,0 +1,239 @@
+use anyhow::{anyhow, Context, Error, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(integer) => integer.fmt(f),
+            Answer::Text(text) => text.fmt(f),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(integer) => serializer.serialize_i128(*integer),
+            Answer::Text(text) => serializer.serialize_str(text),
+        }
+    }
+}
+
+struct AnswerVisitor;
+
+impl<'de> de::Visitor<'de> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an integer or a string")
+    }
+    fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Answer, E> {
+        Ok(value.into())
+    }
+    fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Answer, E> {
+        Ok(value.into())
+    }
+    fn visit_i128<E: de::Error>(self, value: i128) -> std::result::Result<Answer, E> {
+        Ok(value.into())
+    }
+    fn visit_u128<E: de::Error>(self, value: u128) -> std::result::Result<Answer, E> {
+        i128::try_from(value)
+            .map(Answer::Integer)
+            .map_err(|_| E::custom(format!("answer {} does not fit in i128", value)))
+    }
+    fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Answer, E> {
+        Ok(value.into())
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+macro_rules! impl_answer_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.write_str("1"),
+            Part::Two => f.write_str("2"),
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(anyhow!("part must be 1 or 2, got {}", value)),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> Result<Answer>;
+    fn part2(&self, input: &Self::Input) -> Result<Answer>;
+
+    fn solve(&self, input: &str) -> Result<(Answer, Answer)> {
+        let parsed = self.parse(input).context("cannot parse input")?;
+        Ok((self.part1(&parsed)?, self.part2(&parsed)?))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub parse_elapsed: Duration,
+    pub results: Vec<PartResult>,
+}
+
+pub trait DynSolution {
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn run(&self, input: &str, parts: &[Part]) -> Result<Run> {
+        let start = Instant::now();
+        let parsed = self.parse(input).context("cannot parse input")?;
+        let parse_elapsed = start.elapsed();
+        let results = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    Part::One => self.part1(&parsed),
+                    Part::Two => self.part2(&parsed),
+                }
+                .with_context(|| format!("part {} failed", part))?;
+                Ok(PartResult {
+                    part,
+                    answer,
+                    elapsed: start.elapsed(),
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Run {
+            parse_elapsed,
+            results,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<u32>;
+
+        fn parse(&self, input: &str) -> Result<Self::Input> {
+            crate::parse::numbers(input)
+        }
+        fn part1(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(input.iter().sum::<u32>().into())
+        }
+        fn part2(&self, input: &Self::Input) -> Result<Answer> {
+            Ok(format!("{} numbers", input.len()).into())
+        }
+    }
+
+    #[test]
+    fn dyn_solution_runs_selected_parts() {
+        let solution: &dyn DynSolution = &Sum;
+        let results = solution.run("4 5", &[Part::Two]).unwrap().results;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].part, Part::Two);
+        assert_eq!(results[0].answer, Answer::from("2 numbers"));
+    }
+
+    #[test]
+    fn part_from_number() {
+        assert_eq!(Part::try_from(2).unwrap(), Part::Two);
+        assert!(Part::try_from(3).is_err());
+        assert_eq!(u8::from(Part::Two), 2);
+    }
+
+    #[test]
+    fn answer_serializes_as_number_or_string() {
+        let answers = vec![u64::MAX.into(), Answer::Integer(-1), "ABC".into()];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, format!("[{},-1,\"ABC\"]", u64::MAX));
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn solve_returns_both_parts() {
+        let (part1, part2) = Sum.solve("1 2 3").unwrap();
+        assert_eq!(part1, Answer::Integer(6));
+        assert_eq!(part2.to_string(), "3 numbers");
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Answer, Part, Solution};
+
+#[track_caller]
+pub fn assert_part<S: Solution>(
+    solution: &S,
+    input: &str,
+    part: Part,
+    expected: impl Into<Answer>,
+) {
+    let parsed = solution
+        .parse(input)
+        .unwrap_or_else(|error| panic!("cannot parse example: {:#}", error));
+    let answer = match part {
+        Part::One => solution.part1(&parsed),
+        Part::Two => solution.part2(&parsed),
+    }
+    .unwrap_or_else(|error| panic!("part {} failed: {:#}", part, error));
+    assert_eq!(answer, expected.into(), "wrong answer for part {}", part);
+}
+
+#[track_caller]
+pub fn assert_parts<S: Solution>(
+    solution: &S,
+    input: &str,
+    part1: impl Into<Answer>,
+    part2: impl Into<Answer>,
+) {
+    assert_part(solution, input, Part::One, part1);
+    assert_part(solution, input, Part::Two, part2);
+}
@@ -0,0 +1,228 @@
+use crate::registry::RegisteredDay;
+use anyhow::{ensure, Result};
+use aoc::Part;
+use std::{fmt::Write, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let mean = samples.iter().sum::<Duration>() / samples.len() as u32;
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            mean,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Stats,
+    pub parts: Vec<(Part, Stats)>,
+}
+
+impl Timings {
+    fn stages(&self) -> impl Iterator<Item = (String, &Stats)> {
+        std::iter::once(("parse".to_string(), &self.parse)).chain(
+            self.parts
+                .iter()
+                .map(|(part, stats)| (format!("part {}", part), stats)),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayBench {
+    pub year: u16,
+    pub day: u8,
+    pub runs: usize,
+    pub timings: Result<Timings, String>,
+}
+
+impl DayBench {
+    pub fn failed(&self) -> bool {
+        self.timings.is_err()
+    }
+}
+
+fn time_day(registered: &RegisteredDay, runs: usize) -> Result<Timings> {
+    let content = aoc::input::read_input(registered.input_path())?;
+    let mut parse_samples = vec![];
+    let mut part_samples = vec![vec![]; Part::ALL.len()];
+    for _ in 0..runs {
+        let run = registered.solution.run(&content, &Part::ALL)?;
+        parse_samples.push(run.parse_elapsed);
+        for (samples, result) in part_samples.iter_mut().zip(run.results) {
+            samples.push(result.elapsed);
+        }
+    }
+    Ok(Timings {
+        parse: Stats::from_samples(&mut parse_samples),
+        parts: Part::ALL
+            .into_iter()
+            .zip(part_samples.iter_mut())
+            .map(|(part, samples)| (part, Stats::from_samples(samples)))
+            .collect(),
+    })
+}
+
+pub fn bench_day(registered: &RegisteredDay, runs: usize) -> Result<DayBench> {
+    ensure!(runs > 0, "at least one run is needed");
+    Ok(DayBench {
+        year: registered.year,
+        day: registered.day,
+        runs,
+        timings: time_day(registered, runs).map_err(|error| format!("{:#}", error)),
+    })
+}
+
+pub fn render_table(benches: &[DayBench]) -> String {
+    let mut output = String::new();
+    writeln!(
+        output,
+        "{:<4} {:>3}  {:<7} {:>12} {:>12} {:>12}",
+        "year", "day", "stage", "min", "median", "mean"
+    )
+    .unwrap();
+    for bench in benches {
+        let timings = match &bench.timings {
+            Ok(timings) => timings,
+            Err(error) => {
+                let summary = error.lines().next().unwrap_or_default();
+                writeln!(
+                    output,
+                    "{:<4} {:>3}  {:<7} {}",
+                    bench.year, bench.day, "failed", summary
+                )
+                .unwrap();
+                continue;
+            }
+        };
+        for (stage, stats) in timings.stages() {
+            writeln!(
+                output,
+                "{:<4} {:>3}  {:<7} {:>12} {:>12} {:>12}",
+                bench.year,
+                bench.day,
+                stage,
+                format!("{:.2?}", stats.min),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.mean)
+            )
+            .unwrap();
+        }
+    }
+    output
+}
+
+pub fn render_csv(benches: &[DayBench]) -> String {
+    let mut output = String::from("year,day,stage,runs,min_ns,median_ns,mean_ns\n");
+    for bench in benches {
+        let Ok(timings) = &bench.timings else {
+            writeln!(
+                output,
+                "{},{},failed,{},,,",
+                bench.year, bench.day, bench.runs
+            )
+            .unwrap();
+            continue;
+        };
+        for (stage, stats) in timings.stages() {
+            writeln!(
+                output,
+                "{},{},{},{},{},{},{}",
+                bench.year,
+                bench.day,
+                stage,
+                bench.runs,
+                stats.min.as_nanos(),
+                stats.median.as_nanos(),
+                stats.mean.as_nanos()
+            )
+            .unwrap();
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|&value| Duration::from_micros(value))
+            .collect()
+    }
+
+    fn bench() -> DayBench {
+        let stats = Stats::from_samples(&mut micros(&[3, 1, 2]));
+        DayBench {
+            year: 2022,
+            day: 12,
+            runs: 3,
+            timings: Ok(Timings {
+                parse: stats,
+                parts: vec![(Part::One, stats), (Part::Two, stats)],
+            }),
+        }
+    }
+
+    fn failed_bench() -> DayBench {
+        DayBench {
+            year: 2023,
+            day: 13,
+            runs: 3,
+            timings: Err("part 1 failed: part 1 is not solved yet".to_string()),
+        }
+    }
+
+    #[test]
+    fn stats_from_unsorted_samples() {
+        let stats = Stats::from_samples(&mut micros(&[9, 1, 5, 1]));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(5));
+        assert_eq!(stats.mean, Duration::from_micros(4));
+    }
+
+    #[test]
+    fn csv_has_a_row_per_stage() {
+        assert_eq!(
+            render_csv(&[bench()]),
+            "year,day,stage,runs,min_ns,median_ns,mean_ns\n\
+             2022,12,parse,3,1000,2000,2000\n\
+             2022,12,part 1,3,1000,2000,2000\n\
+             2022,12,part 2,3,1000,2000,2000\n"
+        );
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let table = render_table(&[bench()]);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 4);
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|line| line.chars().count() == width));
+        assert!(lines[1].starts_with("2022  12  parse"));
+    }
+
+    #[test]
+    fn failed_days_get_their_own_row() {
+        let benches = [failed_bench(), bench()];
+        let table = render_table(&benches);
+        assert_eq!(table.lines().count(), 5);
+        assert_eq!(
+            table.lines().nth(1).unwrap(),
+            "2023  13  failed  part 1 failed: part 1 is not solved yet"
+        );
+        assert!(render_csv(&benches).contains("\n2023,13,failed,3,,,\n2022,12,parse,"));
+    }
+}
@@ -0,0 +1,228 @@
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+const BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "aoc-runner input fetcher";
+
+pub struct Fetcher {
+    base_url: String,
+    session: String,
+    cache_dir: PathBuf,
+}
+
+#[derive(Debug)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: &str, cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            cache_dir: cache_dir.into(),
+        }
+    }
+    pub fn from_env(cache_dir: impl Into<PathBuf>) -> Result<Self> {
+        Ok(Self::new(BASE_URL, &read_session()?, cache_dir))
+    }
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        cache_path(&self.cache_dir, year, day)
+    }
+    pub fn fetch(&self, year: u16, day: u8) -> Result<Fetched> {
+        ensure!(year >= 2015, "there is no Advent of Code before 2015");
+        ensure!((1..=25).contains(&day), "day must be between 1 and 25");
+        let path = self.cache_path(year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let content = match ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+        {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("cannot read response from {}", url))?,
+            Err(ureq::Error::Status(status, _)) => {
+                bail!("{} answered with status {}", url, status)
+            }
+            Err(error) => return Err(error).with_context(|| format!("cannot fetch {}", url)),
+        };
+        write_new(&path, &content)?;
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+pub fn cache_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{:02}.txt", day))
+}
+
+fn write_new(path: &Path, content: &str) -> Result<()> {
+    let dir = path.parent().expect("cache path has a parent");
+    fs::create_dir_all(dir).with_context(|| format!("cannot create {}", dir.display()))?;
+    let partial = path.with_extension("part");
+    fs::write(&partial, content).with_context(|| format!("cannot write {}", partial.display()))?;
+    fs::rename(&partial, path).with_context(|| format!("cannot write {}", path.display()))
+}
+
+pub fn config_path() -> Option<PathBuf> {
+    let config_dir = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+    Some(config_dir.join("aoc").join("session"))
+}
+
+fn read_session() -> Result<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session.trim().to_string());
+    }
+    let path = config_path().ok_or(anyhow!("cannot locate the config directory"))?;
+    if !path.exists() {
+        bail!(
+            "no session token: set {} or write it to {}",
+            SESSION_VAR,
+            path.display()
+        );
+    }
+    read_session_file(&path)
+}
+
+pub fn read_session_file(path: &Path) -> Result<String> {
+    check_permissions(path)?;
+    let session = fs::read_to_string(path)
+        .with_context(|| format!("cannot read session file {}", path.display()))?;
+    let session = session.trim();
+    ensure!(
+        !session.is_empty(),
+        "session file {} is empty",
+        path.display()
+    );
+    Ok(session.to_string())
+}
+
+#[cfg(unix)]
+fn check_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mode = fs::metadata(path)
+        .with_context(|| format!("cannot read session file {}", path.display()))?
+        .permissions()
+        .mode();
+    ensure!(
+        mode & 0o077 == 0,
+        "session file {} is accessible by other users (mode {:o}), run chmod 600 on it",
+        path.display(),
+        mode & 0o777
+    );
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn check_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        sync::{Arc, Mutex},
+        thread,
+    };
+    use tiny_http::{Response, Server};
+
+    struct StandIn {
+        url: String,
+        requests: Arc<Mutex<Vec<(String, String)>>>,
+    }
+
+    fn serve(status: u16, body: &'static str) -> StandIn {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let log = requests.clone();
+        thread::spawn(move || {
+            for request in server.incoming_requests() {
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.equiv("Cookie"))
+                    .map(|header| header.value.to_string())
+                    .unwrap_or_default();
+                log.lock()
+                    .unwrap()
+                    .push((request.url().to_string(), cookie));
+                let response = Response::from_string(body).with_status_code(status);
+                request.respond(response).unwrap();
+            }
+        });
+        StandIn { url, requests }
+    }
+
+    #[test]
+    fn downloads_once_then_uses_cache() {
+        let stand_in = serve(200, "1 2 3\n");
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher::new(&stand_in.url, "secret", cache.path());
+
+        let Fetched::Downloaded(path) = fetcher.fetch(2023, 25).unwrap() else {
+            panic!("first fetch must download");
+        };
+        assert_eq!(path, cache.path().join("2023").join("day25.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2 3\n");
+        assert!(matches!(
+            fetcher.fetch(2023, 25).unwrap(),
+            Fetched::Cached(_)
+        ));
+
+        let requests = stand_in.requests.lock().unwrap();
+        assert_eq!(
+            *requests,
+            [(
+                "/2023/day/25/input".to_string(),
+                "session=secret".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn error_status_leaves_cache_empty() {
+        let stand_in = serve(400, "Please log in");
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher::new(&stand_in.url, "expired", cache.path());
+        let error = fetcher.fetch(2022, 1).unwrap_err();
+        assert!(error.to_string().contains("status 400"));
+        assert!(!fetcher.cache_path(2022, 1).exists());
+    }
+
+    #[test]
+    fn rejects_days_outside_advent() {
+        let fetcher = Fetcher::new("http://127.0.0.1:9", "secret", "unused");
+        assert!(fetcher.fetch(2023, 0).is_err());
+        assert!(fetcher.fetch(2023, 26).is_err());
+        assert!(fetcher.fetch(2014, 1).is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn session_file_must_be_private() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session");
+        fs::write(&path, "secret\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        assert!(read_session_file(&path).is_err());
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+        assert_eq!(read_session_file(&path).unwrap(), "secret");
+    }
+}
@@ -0,0 +1,327 @@
+mod bench;
+mod fetch;
+#[cfg(test)]
+mod malformed;
+mod registry;
+mod report;
+mod scaffold;
+mod store;
+
+use anyhow::{bail, Context, Result};
+use aoc::Part;
+use aoc2023_day07::{CardRules, HandBids, TieBreak};
+use clap::{Parser, Subcommand};
+use registry::RegisteredDay;
+use report::{Format, Report};
+use std::{
+    fs,
+    io::{stdin, Read},
+    path::PathBuf,
+};
+use store::{AnswerStore, Entry, Status};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code solution runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solution for a single day
+    Run {
+        year: u16,
+        day: u8,
+        /// Only run this part (1 or 2)
+        #[arg(long)]
+        part: Option<u8>,
+        /// Read the puzzle input from this file, or from stdin when given "-"
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Record the current answers for a day as known-good
+    Record {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from this file, or from stdin when given "-"
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Re-run every solved day and compare against the recorded answers
+    Verify {
+        /// Only verify days of this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Time parsing and each part of every solved day
+    Bench {
+        /// Only benchmark days of this year
+        #[arg(long)]
+        year: Option<u16>,
+        /// Only benchmark this day
+        #[arg(long)]
+        day: Option<u8>,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10)]
+        runs: usize,
+        /// Also write the results as CSV to this file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+    /// Download a puzzle input into the local cache, unless it is cached already;
+    /// days without an input file read it from there
+    Fetch { year: u16, day: u8 },
+    /// Create and register a new day from the solution template
+    New { year: u16, day: u8 },
+    /// List how 2023 day 7 ranks every hand under a set of card rules
+    Standings {
+        /// Card labels from weakest to strongest
+        #[arg(long, default_value = "23456789TJQKA")]
+        order: String,
+        /// Labels that stand in for any other card, like J in part 2
+        #[arg(long, default_value = "")]
+        wildcards: String,
+        /// How hands of the same type are ordered: positional or sorted
+        #[arg(long, default_value = "positional")]
+        tie_break: TieBreak,
+        /// Read the puzzle input from this file, or from stdin when given "-"
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Also write the standings as CSV to this file
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
+}
+
+fn store_path() -> PathBuf {
+    registry::workspace_root().join("answers.json")
+}
+
+fn read_input(input: Option<PathBuf>, default: PathBuf) -> Result<String> {
+    match input {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut content = String::new();
+            stdin()
+                .read_to_string(&mut content)
+                .context("cannot read input from stdin")?;
+            Ok(content)
+        }
+        Some(path) => aoc::input::read_input(path),
+        None => aoc::input::read_input(default),
+    }
+}
+
+fn run(year: u16, day: u8, part: Option<u8>, input: Option<PathBuf>, format: Format) -> Result<()> {
+    let registered = registry::find_day(year, day)?;
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    };
+    let content = read_input(input, registered.input_path())?;
+    let reports = registered
+        .solution
+        .run(&content, &parts)
+        .with_context(|| format!("{} day {} failed", year, day))?
+        .results
+        .into_iter()
+        .map(|result| Report { year, day, result })
+        .collect::<Vec<_>>();
+    print!("{}", report::render(format, &reports)?);
+    Ok(())
+}
+
+fn record(year: u16, day: u8, input: Option<PathBuf>) -> Result<()> {
+    let registered = registry::find_day(year, day)?;
+    let content = read_input(input, registered.input_path())?;
+    let input_sha256 = store::hash_input(&content);
+    let results = registered
+        .solution
+        .run(&content, &Part::ALL)
+        .with_context(|| format!("{} day {} failed", year, day))?
+        .results;
+    let mut store = AnswerStore::load(store_path())?;
+    for result in results {
+        println!(
+            "{} day {} part {}: recorded {}",
+            year, day, result.part, result.answer
+        );
+        store.record(Entry {
+            year,
+            day,
+            part: result.part.into(),
+            input_sha256: input_sha256.clone(),
+            answer: result.answer,
+        });
+    }
+    store.save(store_path())
+}
+
+fn verify_day(registered: &RegisteredDay, store: &AnswerStore) -> Result<Vec<(Part, Status)>> {
+    let content = aoc::input::read_input(registered.input_path())?;
+    let input_sha256 = store::hash_input(&content);
+    let results = registered.solution.run(&content, &Part::ALL)?.results;
+    Ok(results
+        .into_iter()
+        .map(|result| {
+            let status = store.check(
+                registered.year,
+                registered.day,
+                result.part,
+                &input_sha256,
+                &result.answer,
+            );
+            (result.part, status)
+        })
+        .collect())
+}
+
+fn verify(year: Option<u16>, day: Option<u8>) -> Result<()> {
+    let store = AnswerStore::load(store_path())?;
+    let (mut passed, mut failed, mut changed, mut unrecorded) = (0, 0, 0, 0);
+    for registered in registry::filter_days(year, day) {
+        if !registered.solved {
+            println!(
+                "{} day {}: skipped (not solved yet)",
+                registered.year, registered.day
+            );
+            continue;
+        }
+        let statuses = match verify_day(registered, &store) {
+            Ok(statuses) => statuses,
+            Err(error) => {
+                failed += Part::ALL.len();
+                println!(
+                    "{} day {}: fail ({:#})",
+                    registered.year, registered.day, error
+                );
+                continue;
+            }
+        };
+        for (part, status) in statuses {
+            let message = match status {
+                Status::Pass => {
+                    passed += 1;
+                    "pass".to_string()
+                }
+                Status::Fail { expected } => {
+                    failed += 1;
+                    format!("fail (expected {})", expected)
+                }
+                Status::Changed => {
+                    changed += 1;
+                    "changed (input differs from the recorded one)".to_string()
+                }
+                Status::Unrecorded => {
+                    unrecorded += 1;
+                    "unrecorded".to_string()
+                }
+            };
+            println!(
+                "{} day {} part {}: {}",
+                registered.year, registered.day, part, message
+            );
+        }
+    }
+    println!(
+        "{} passed, {} failed, {} changed, {} unrecorded",
+        passed, failed, changed, unrecorded
+    );
+    if failed > 0 {
+        bail!("{} answers failed verification", failed);
+    }
+    Ok(())
+}
+
+fn bench(year: Option<u16>, day: Option<u8>, runs: usize, csv: Option<PathBuf>) -> Result<()> {
+    let benches = registry::filter_days(year, day)
+        .filter(|registered| registered.solved)
+        .map(|registered| bench::bench_day(registered, runs))
+        .collect::<Result<Vec<_>>>()?;
+    print!("{}", bench::render_table(&benches));
+    if let Some(path) = csv {
+        fs::write(&path, bench::render_csv(&benches))
+            .with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    let failed = benches.iter().filter(|bench| bench.failed()).count();
+    if failed > 0 {
+        bail!("{} days failed to run", failed);
+    }
+    Ok(())
+}
+
+fn fetch(year: u16, day: u8) -> Result<()> {
+    let cache_dir = registry::input_cache_dir(&registry::workspace_root());
+    match fetch::Fetcher::from_env(cache_dir)?.fetch(year, day)? {
+        fetch::Fetched::Cached(path) => {
+            println!("{} day {}: already cached at {}", year, day, path.display())
+        }
+        fetch::Fetched::Downloaded(path) => {
+            println!("{} day {}: downloaded to {}", year, day, path.display())
+        }
+    }
+    Ok(())
+}
+
+fn new_day(year: u16, day: u8) -> Result<()> {
+    scaffold::new_day(&registry::workspace_root(), year, day)?;
+    println!("{} day {}: created {}/day{:02}", year, day, year, day);
+    println!(
+        "paste the example into {0}/day{1:02}/input2.txt, and run `aoc fetch {0} {2}` \
+         to download the puzzle input (or put it in {0}/day{1:02}/input.txt)",
+        year, day, day
+    );
+    println!(
+        "verify and bench skip the day until `unsolved` is removed from its entry in \
+         runner/src/registry.rs"
+    );
+    Ok(())
+}
+
+fn standings(rules: CardRules, input: Option<PathBuf>, csv: Option<PathBuf>) -> Result<()> {
+    let registered = registry::find_day(2023, 7)?;
+    let content = read_input(input, registered.input_path())?;
+    let hand_bids = content.parse::<HandBids>()?;
+    let standings = hand_bids.standings(&rules)?;
+    print!("{}", aoc2023_day07::render_table(&standings));
+    if let Some(path) = csv {
+        fs::write(&path, aoc2023_day07::render_csv(&standings))
+            .with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+            format,
+        } => run(year, day, part, input, format),
+        Command::Record { year, day, input } => record(year, day, input),
+        Command::Verify { year, day } => verify(year, day),
+        Command::Bench {
+            year,
+            day,
+            runs,
+            csv,
+        } => bench(year, day, runs, csv),
+        Command::Fetch { year, day } => fetch(year, day),
+        Command::New { year, day } => new_day(year, day),
+        Command::Standings {
+            order,
+            wildcards,
+            tie_break,
+            input,
+            csv,
+        } => standings(CardRules::new(&order, &wildcards, tie_break)?, input, csv),
+    }
+}
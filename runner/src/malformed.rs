@@ -0,0 +1,161 @@
+use crate::registry::DAYS;
+use aoc::Part;
+use std::{sync::mpsc, thread, time::Duration};
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+const LONG_NUMBER_DIGITS: usize = 3;
+
+fn middle_line(input: &str) -> usize {
+    input.lines().count() / 2
+}
+
+fn map_line(input: &str, index: usize, func: impl Fn(&str) -> String) -> String {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == index {
+                func(line)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Short numbers are usually counts that drive a simulation step by step, so
+// only numbers that are already long are replaced to keep every day fast.
+fn replace_long_numbers(input: &str, replacement: &str) -> String {
+    let mut output = String::new();
+    let mut rest = input;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()) {
+        output.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if end >= LONG_NUMBER_DIGITS {
+            output.push_str(replacement);
+        } else {
+            output.push_str(&rest[..end]);
+        }
+        rest = &rest[end..];
+    }
+    output.push_str(rest);
+    output
+}
+
+fn mutations(input: &str) -> Vec<(&'static str, String)> {
+    let middle = middle_line(input);
+    let half = input.floor_char_boundary(input.len() / 2);
+    vec![
+        ("empty", String::new()),
+        ("blank lines", "\n\n\n".to_string()),
+        ("garbage", "garbage".to_string()),
+        ("first half", input[..half].to_string()),
+        (
+            "first line removed",
+            input.lines().skip(1).collect::<Vec<_>>().join("\n"),
+        ),
+        (
+            "middle line halved",
+            map_line(input, middle, |line| {
+                line[..line.floor_char_boundary(line.len() / 2)].to_string()
+            }),
+        ),
+        (
+            "digits replaced in middle line",
+            map_line(input, middle, |line| {
+                line.replace(|c: char| c.is_ascii_digit(), "x")
+            }),
+        ),
+        (
+            "non-ASCII character in middle line",
+            map_line(input, middle, |line| {
+                let at = line.floor_char_boundary(line.len() / 2);
+                format!("{}é{}", &line[..at], &line[at..])
+            }),
+        ),
+        ("ones become nines", input.replace('1', "9")),
+        ("letters uppercased", input.to_ascii_uppercase()),
+        (
+            "long numbers near u32::MAX",
+            replace_long_numbers(input, &(u32::MAX - 1).to_string()),
+        ),
+        (
+            "long numbers near u64::MAX",
+            replace_long_numbers(input, &(u64::MAX - 1).to_string()),
+        ),
+        (
+            "letters shifted",
+            input
+                .chars()
+                .map(|c| match c {
+                    'a'..='y' | 'A'..='Y' => (c as u8 + 1) as char,
+                    _ => c,
+                })
+                .collect(),
+        ),
+        (
+            "lines reversed",
+            input.lines().rev().collect::<Vec<_>>().join("\n"),
+        ),
+    ]
+}
+
+fn run_without_panic(index: usize, name: &str, input: String) -> Option<String> {
+    let registered = &DAYS[index];
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let outcome = DAYS[index].solution.run(&input, &Part::ALL).map(|_| ());
+        sender
+            .send(outcome.map_err(|error| format!("{:#}", error)))
+            .ok();
+    });
+    let problem = match receiver.recv_timeout(TIMEOUT) {
+        Ok(_) => return None,
+        Err(mpsc::RecvTimeoutError::Timeout) => "did not finish",
+        Err(mpsc::RecvTimeoutError::Disconnected) => "panicked",
+    };
+    Some(format!(
+        "{} day {} {} on {} input",
+        registered.year, registered.day, problem, name
+    ))
+}
+
+#[test]
+fn no_day_panics_on_malformed_input() {
+    let mut failures = vec![];
+    for (index, registered) in DAYS.iter().enumerate() {
+        let input_path = registered.input_path();
+        let example_path = input_path.with_file_name("input2.txt");
+        let Ok(input) =
+            aoc::input::read_input(&input_path).or_else(|_| aoc::input::read_input(&example_path))
+        else {
+            failures.push(format!(
+                "{} day {} has neither {} nor {}",
+                registered.year,
+                registered.day,
+                input_path.display(),
+                example_path.display()
+            ));
+            continue;
+        };
+        for (name, mutated) in mutations(&input) {
+            failures.extend(run_without_panic(index, name, mutated));
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn errors_name_the_failing_stage() {
+    let registered = crate::registry::find_day(2023, 4).unwrap();
+    let error = registered
+        .solution
+        .run("garbage", &Part::ALL)
+        .err()
+        .unwrap();
+    assert!(format!("{:#}", error).starts_with("cannot parse input: line 1, column 1"));
+}
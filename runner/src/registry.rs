@@ -0,0 +1,135 @@
+use crate::fetch;
+use anyhow::{anyhow, Result};
+use aoc::DynSolution;
+use std::path::{Path, PathBuf};
+
+pub struct RegisteredDay {
+    pub year: u16,
+    pub day: u8,
+    pub input: &'static str,
+    pub solution: &'static dyn DynSolution,
+    pub solved: bool,
+}
+
+impl RegisteredDay {
+    pub fn input_path(&self) -> PathBuf {
+        self.input_path_in(&workspace_root())
+    }
+    fn input_path_in(&self, root: &Path) -> PathBuf {
+        let path = root.join(self.input);
+        let cached = fetch::cache_path(&input_cache_dir(root), self.year, self.day);
+        if !path.exists() && cached.exists() {
+            cached
+        } else {
+            path
+        }
+    }
+}
+
+pub fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+pub fn input_cache_dir(root: &Path) -> PathBuf {
+    root.join(".cache").join("inputs")
+}
+
+macro_rules! register {
+    ($year:literal, $day:literal, $solution:path, $input:literal) => {
+        RegisteredDay {
+            year: $year,
+            day: $day,
+            input: $input,
+            solution: &$solution,
+            solved: true,
+        }
+    };
+    ($year:literal, $day:literal, $solution:path, $input:literal, unsolved) => {
+        RegisteredDay {
+            solved: false,
+            ..register!($year, $day, $solution, $input)
+        }
+    };
+}
+
+pub const DAYS: &[RegisteredDay] = &[
+    register!(2022, 1, aoc2022_day01::Day01, "2022/day01/1.txt"),
+    register!(2022, 2, aoc2022_day02::Day02, "2022/day02/input.txt"),
+    register!(2022, 3, aoc2022_day03::Day03, "2022/day03/input.txt"),
+    register!(2022, 4, aoc2022_day04::Day04, "2022/day04/input.txt"),
+    register!(2022, 5, aoc2022_day05::Day05, "2022/day05/input.txt"),
+    register!(2022, 6, aoc2022_day06::Day06, "2022/day06/input.txt"),
+    register!(2022, 7, aoc2022_day07::Day07, "2022/day07/input.txt"),
+    register!(2022, 8, aoc2022_day08::Day08, "2022/day08/input.txt"),
+    register!(2022, 9, aoc2022_day09::Day09, "2022/day09/input.txt"),
+    register!(2022, 10, aoc2022_day10::Day10, "2022/day10/input.txt"),
+    register!(2022, 11, aoc2022_day11::Day11, "2022/day11/input.txt"),
+    register!(2022, 12, aoc2022_day12::Day12, "2022/day12/input.txt"),
+    register!(2023, 4, aoc2023_day04::Day04, "2023/day04/input.txt"),
+    register!(2023, 5, aoc2023_day05::Day05, "2023/day05/input1.txt"),
+    register!(2023, 6, aoc2023_day06::Day06, "2023/day06/input1.txt"),
+    register!(2023, 7, aoc2023_day07::Day07, "2023/day07/input.txt"),
+    register!(2023, 8, aoc2023_day08::Day08, "2023/day08/input.txt"),
+    register!(2023, 9, aoc2023_day09::Day09, "2023/day09/input.txt"),
+    register!(2023, 10, aoc2023_day10::Day10, "2023/day10/input.txt"),
+    register!(2023, 11, aoc2023_day11::Day11, "2023/day11/input.txt"),
+];
+
+pub fn find_day(year: u16, day: u8) -> Result<&'static RegisteredDay> {
+    DAYS.iter()
+        .find(|registered| registered.year == year && registered.day == day)
+        .ok_or(anyhow!("no solution registered for {} day {}", year, day))
+}
+
+pub fn filter_days(
+    year: Option<u16>,
+    day: Option<u8>,
+) -> impl Iterator<Item = &'static RegisteredDay> {
+    DAYS.iter().filter(move |registered| {
+        year.is_none_or(|year| registered.year == year)
+            && day.is_none_or(|day| registered.day == day)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn days_are_unique() {
+        let keys = DAYS
+            .iter()
+            .map(|registered| (registered.year, registered.day))
+            .collect::<HashSet<_>>();
+        assert_eq!(keys.len(), DAYS.len());
+    }
+
+    #[test]
+    fn filter_by_year_and_day() {
+        assert_eq!(filter_days(Some(2023), None).count(), 8);
+        assert_eq!(filter_days(None, Some(4)).count(), 2);
+        assert_eq!(filter_days(Some(2022), Some(12)).count(), 1);
+    }
+
+    #[test]
+    fn input_falls_back_to_fetched_cache() {
+        let root = tempfile::tempdir().unwrap();
+        let registered = find_day(2023, 4).unwrap();
+        let input = root.path().join("2023/day04/input.txt");
+        let cached = root.path().join(".cache/inputs/2023/day04.txt");
+        assert_eq!(registered.input_path_in(root.path()), input);
+        std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+        std::fs::write(&cached, "fetched").unwrap();
+        assert_eq!(registered.input_path_in(root.path()), cached);
+        std::fs::create_dir_all(input.parent().unwrap()).unwrap();
+        std::fs::write(&input, "local").unwrap();
+        assert_eq!(registered.input_path_in(root.path()), input);
+    }
+
+    #[test]
+    fn unknown_day_is_an_error() {
+        assert!(find_day(2022, 25).is_err());
+        assert!(find_day(2023, 4).is_ok());
+    }
+}
@@ -0,0 +1,123 @@
+use anyhow::Result;
+use aoc::{Answer, PartResult};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub result: PartResult,
+}
+
+#[derive(Serialize)]
+struct JsonReport<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ns: u128,
+}
+
+impl<'a> From<&'a Report> for JsonReport<'a> {
+    fn from(report: &'a Report) -> Self {
+        JsonReport {
+            year: report.year,
+            day: report.day,
+            part: report.result.part.into(),
+            answer: &report.result.answer,
+            elapsed_ns: report.result.elapsed.as_nanos(),
+        }
+    }
+}
+
+fn render_text(reports: &[Report]) -> String {
+    let mut output = String::new();
+    for report in reports {
+        let answer = report.result.answer.to_string();
+        let separator = if answer.contains('\n') { "\n" } else { " " };
+        writeln!(
+            output,
+            "{} day {} part {} ({:.2?}):{}{}",
+            report.year, report.day, report.result.part, report.result.elapsed, separator, answer
+        )
+        .unwrap();
+    }
+    output
+}
+
+fn render_json(reports: &[Report]) -> Result<String> {
+    let reports = reports.iter().map(JsonReport::from).collect::<Vec<_>>();
+    Ok(serde_json::to_string_pretty(&reports)? + "\n")
+}
+
+pub fn render(format: Format, reports: &[Report]) -> Result<String> {
+    match format {
+        Format::Text => Ok(render_text(reports)),
+        Format::Json => render_json(reports),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Part;
+    use std::time::Duration;
+
+    fn reports() -> Vec<Report> {
+        vec![
+            Report {
+                year: 2022,
+                day: 1,
+                result: PartResult {
+                    part: Part::One,
+                    answer: Answer::Integer(68923),
+                    elapsed: Duration::from_micros(1500),
+                },
+            },
+            Report {
+                year: 2022,
+                day: 5,
+                result: PartResult {
+                    part: Part::Two,
+                    answer: Answer::from("HRFTQVWNN"),
+                    elapsed: Duration::from_nanos(42),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn text_lists_one_line_per_part() {
+        assert_eq!(
+            render(Format::Text, &reports()).unwrap(),
+            "2022 day 1 part 1 (1.50ms): 68923\n2022 day 5 part 2 (42.00ns): HRFTQVWNN\n"
+        );
+    }
+
+    #[test]
+    fn text_puts_multiline_answers_below_header() {
+        let mut reports = reports();
+        reports[1].result.answer = Answer::from("#..\n.#.");
+        let output = render(Format::Text, &reports[1..]).unwrap();
+        assert_eq!(output, "2022 day 5 part 2 (42.00ns):\n#..\n.#.\n");
+    }
+
+    #[test]
+    fn json_keeps_answer_types() {
+        let output = render(Format::Json, &reports()).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(value[0]["year"], 2022);
+        assert_eq!(value[0]["part"], 1);
+        assert_eq!(value[0]["answer"], 68923);
+        assert_eq!(value[0]["elapsed_ns"], 1_500_000);
+        assert_eq!(value[1]["answer"], "HRFTQVWNN");
+    }
+}
@@ -0,0 +1,282 @@
+use anyhow::{bail, ensure, Context, Result};
+use regex::Regex;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const CARGO_TEMPLATE: &str = r#"[package]
+name = "aoc{year}-day{day}"
+version.workspace = true
+edition.workspace = true
+
+[dependencies]
+aoc.workspace = true
+anyhow.workspace = true
+"#;
+
+const LIB_TEMPLATE: &str = r#"use anyhow::{anyhow, Result};
+use aoc::{Answer, Solution};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+    fn part1(&self, _lines: &Self::Input) -> Result<Answer> {
+        Err(anyhow!("part 1 is not solved yet"))
+    }
+    fn part2(&self, _lines: &Self::Input) -> Result<Answer> {
+        Err(anyhow!("part 2 is not solved yet"))
+    }
+}
+"#;
+
+const EXAMPLES_TEMPLATE: &str = r#"use aoc::testing::assert_parts;
+use aoc{year}_day{day}::Day{day};
+
+#[test]
+#[ignore = "paste the puzzle example into input2.txt and fill in its answers"]
+fn example() {
+    assert_parts(&Day{day}, include_str!("../input2.txt"), 0, 0);
+}
+"#;
+
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{:02}", day))
+}
+
+fn insert_sorted(
+    content: &str,
+    entry: &Regex,
+    key: (u16, u8),
+    line: &str,
+    file: &Path,
+) -> Result<String> {
+    let mut lines = content.lines().collect::<Vec<_>>();
+    let entries = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let captures = entry.captures(line)?;
+            Some((
+                index,
+                (captures[1].parse().ok()?, captures[2].parse().ok()?),
+            ))
+        })
+        .collect::<Vec<(usize, (u16, u8))>>();
+    let Some(&(last, _)) = entries.last() else {
+        bail!(
+            "cannot find where to register the day in {}",
+            file.display()
+        );
+    };
+    if entries.iter().any(|&(_, existing)| existing == key) {
+        bail!(
+            "{} day {} is already registered in {}",
+            key.0,
+            key.1,
+            file.display()
+        );
+    }
+    let index = entries
+        .iter()
+        .find(|&&(_, existing)| existing > key)
+        .map_or(last + 1, |&(index, _)| index);
+    lines.insert(index, line);
+    Ok(lines.join("\n") + "\n")
+}
+
+fn register_in(
+    root: &Path,
+    file: &str,
+    entry: &str,
+    key: (u16, u8),
+    line: String,
+) -> Result<(PathBuf, String)> {
+    let path = root.join(file);
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("cannot read {}", path.display()))?;
+    let entry = Regex::new(entry).unwrap();
+    let updated = insert_sorted(&content, &entry, key, &line, &path)?;
+    Ok((path, updated))
+}
+
+fn write_crate(dir: &Path, year: u16, day: u8) -> Result<()> {
+    fs::create_dir_all(dir.join("src"))?;
+    fs::create_dir_all(dir.join("tests"))?;
+    let files = [
+        ("Cargo.toml", render(CARGO_TEMPLATE, year, day)),
+        ("src/lib.rs", render(LIB_TEMPLATE, year, day)),
+        ("tests/examples.rs", render(EXAMPLES_TEMPLATE, year, day)),
+        ("input2.txt", String::new()),
+    ];
+    for (name, content) in files {
+        let path = dir.join(name);
+        fs::write(&path, content).with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn partial_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().expect("file has a name").to_os_string();
+    name.push(".part");
+    path.with_file_name(name)
+}
+
+fn stage(updates: &[(PathBuf, String)]) -> Result<()> {
+    for (index, (path, content)) in updates.iter().enumerate() {
+        let partial = partial_path(path);
+        if let Err(error) = fs::write(&partial, content) {
+            for (path, _) in &updates[..=index] {
+                fs::remove_file(partial_path(path)).ok();
+            }
+            return Err(error).with_context(|| format!("cannot write {}", partial.display()));
+        }
+    }
+    Ok(())
+}
+
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<()> {
+    ensure!(year >= 2015, "there is no Advent of Code before 2015");
+    ensure!((1..=25).contains(&day), "day must be between 1 and 25");
+    let relative_dir = format!("{}/day{:02}", year, day);
+    let dir = root.join(&relative_dir);
+    ensure!(!dir.exists(), "{} already exists", dir.display());
+
+    let key = (year, day);
+    let indent = "    ";
+    let updates = [
+        register_in(
+            root,
+            "Cargo.toml",
+            r#"^\s*"(\d{4})/day(\d{2})",$"#,
+            key,
+            format!("{}\"{}\",", indent, relative_dir),
+        )?,
+        register_in(
+            root,
+            "runner/Cargo.toml",
+            r"^aoc(\d{4})-day(\d{2}) = ",
+            key,
+            format!(
+                "aoc{}-day{:02} = {{ path = \"../{}\" }}",
+                year, day, relative_dir
+            ),
+        )?,
+        register_in(
+            root,
+            "runner/src/registry.rs",
+            r"^\s*register!\((\d{4}), (\d+),",
+            key,
+            format!(
+                "{}register!({}, {}, aoc{}_day{:02}::Day{:02}, \"{}/input.txt\", unsolved),",
+                indent, year, day, year, day, day, relative_dir
+            ),
+        )?,
+    ];
+
+    if let Err(error) = write_crate(&dir, year, day).and_then(|()| stage(&updates)) {
+        fs::remove_dir_all(&dir).ok();
+        return Err(error);
+    }
+    for (path, _) in &updates {
+        let partial = partial_path(path);
+        fs::rename(&partial, path).with_context(|| format!("cannot write {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::workspace_root;
+
+    fn workspace_copy() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("runner/src")).unwrap();
+        for file in ["Cargo.toml", "runner/Cargo.toml", "runner/src/registry.rs"] {
+            fs::copy(workspace_root().join(file), dir.path().join(file)).unwrap();
+        }
+        dir
+    }
+
+    fn read(root: &Path, file: &str) -> String {
+        fs::read_to_string(root.join(file)).unwrap()
+    }
+
+    #[test]
+    fn creates_and_registers_day() {
+        let workspace = workspace_copy();
+        let root = workspace.path();
+        new_day(root, 2023, 13).unwrap();
+
+        let lib = read(root, "2023/day13/src/lib.rs");
+        assert!(lib.contains("impl Solution for Day13"));
+        assert!(read(root, "2023/day13/Cargo.toml").contains("name = \"aoc2023-day13\""));
+        assert!(read(root, "2023/day13/tests/examples.rs").contains("use aoc2023_day13::Day13;"));
+        assert!(root.join("2023/day13/input2.txt").exists());
+
+        let members = read(root, "Cargo.toml");
+        assert!(members.contains("    \"2023/day12\",\n    \"2023/day13\",\n"));
+        assert!(read(root, "runner/Cargo.toml")
+            .contains("aoc2023-day13 = { path = \"../2023/day13\" }"));
+        let registry = read(root, "runner/src/registry.rs");
+        assert!(registry.contains(
+            "Day11, \"2023/day11/input.txt\"),\n    \
+             register!(2023, 13, aoc2023_day13::Day13, \"2023/day13/input.txt\", unsolved),\n];"
+        ));
+    }
+
+    #[test]
+    fn keeps_registrations_sorted() {
+        let workspace = workspace_copy();
+        let root = workspace.path();
+        new_day(root, 2022, 13).unwrap();
+        let registry = read(root, "runner/src/registry.rs");
+        let day12 = registry.find("register!(2022, 12,").unwrap();
+        let day13 = registry.find("register!(2022, 13,").unwrap();
+        let next_year = registry.find("register!(2023, 4,").unwrap();
+        assert!(day12 < day13 && day13 < next_year);
+    }
+
+    #[test]
+    fn refuses_to_clobber_existing_day() {
+        let workspace = workspace_copy();
+        let root = workspace.path();
+        new_day(root, 2023, 13).unwrap();
+        let registry = read(root, "runner/src/registry.rs");
+        assert!(new_day(root, 2023, 13).is_err());
+        assert_eq!(read(root, "runner/src/registry.rs"), registry);
+
+        fs::create_dir_all(root.join("2023/day12")).unwrap();
+        let error = new_day(root, 2023, 12).unwrap_err();
+        assert!(error.to_string().contains("already exists"));
+    }
+
+    #[test]
+    fn refuses_already_registered_day() {
+        let workspace = workspace_copy();
+        let root = workspace.path();
+        let members = read(root, "Cargo.toml");
+        let error = new_day(root, 2022, 1).unwrap_err();
+        assert!(error.to_string().contains("already registered"));
+        assert!(!root.join("2022/day01").exists());
+        assert_eq!(read(root, "Cargo.toml"), members);
+    }
+
+    #[test]
+    fn leaves_no_partial_files() {
+        let workspace = workspace_copy();
+        let root = workspace.path();
+        new_day(root, 2023, 13).unwrap();
+        for file in ["Cargo.toml", "runner/Cargo.toml", "runner/src/registry.rs"] {
+            assert!(!partial_path(&root.join(file)).exists());
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{Context, Result};
+use aoc::{Answer, Part};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt::Write, fs, io::ErrorKind, path::Path};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_sha256: String,
+    pub answer: Answer,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail { expected: Answer },
+    Changed,
+    Unrecorded,
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    entries: Vec<Entry>,
+}
+
+pub fn hash_input(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::new(), |mut hash, byte| {
+            write!(hash, "{:02x}", byte).unwrap();
+            hash
+        })
+}
+
+impl AnswerStore {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) if error.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => {
+                return Err(error)
+                    .with_context(|| format!("cannot read answer store {}", path.display()))
+            }
+        };
+        let entries = serde_json::from_str(&content)
+            .with_context(|| format!("invalid answer store {}", path.display()))?;
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let content = serde_json::to_string_pretty(&self.entries)? + "\n";
+        fs::write(path, content)
+            .with_context(|| format!("cannot write answer store {}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Entry> {
+        let part = u8::from(part);
+        self.entries
+            .iter()
+            .find(|entry| entry.year == year && entry.day == day && entry.part == part)
+    }
+
+    pub fn record(&mut self, entry: Entry) {
+        self.entries.retain(|existing| {
+            (existing.year, existing.day, existing.part) != (entry.year, entry.day, entry.part)
+        });
+        self.entries.push(entry);
+        self.entries
+            .sort_by_key(|entry| (entry.year, entry.day, entry.part));
+    }
+
+    pub fn check(
+        &self,
+        year: u16,
+        day: u8,
+        part: Part,
+        input_sha256: &str,
+        answer: &Answer,
+    ) -> Status {
+        match self.get(year, day, part) {
+            None => Status::Unrecorded,
+            Some(entry) if entry.input_sha256 != input_sha256 => Status::Changed,
+            Some(entry) if &entry.answer == answer => Status::Pass,
+            Some(entry) => Status::Fail {
+                expected: entry.answer.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn store() -> AnswerStore {
+        let mut store = AnswerStore::default();
+        store.record(Entry {
+            year: 2022,
+            day: 1,
+            part: 1,
+            input_sha256: hash_input("1\n2\n"),
+            answer: Answer::Integer(3),
+        });
+        store
+    }
+
+    #[test]
+    fn hash_is_hex_sha256() {
+        assert_eq!(
+            hash_input(""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn check_compares_answer_and_input() {
+        let store = store();
+        let hash = hash_input("1\n2\n");
+        let check = |part, hash: &str, answer: i128| {
+            store.check(2022, 1, part, hash, &Answer::Integer(answer))
+        };
+        assert_eq!(check(Part::One, &hash, 3), Status::Pass);
+        assert_eq!(
+            check(Part::One, &hash, 4),
+            Status::Fail {
+                expected: Answer::Integer(3)
+            }
+        );
+        assert_eq!(check(Part::One, &hash_input("1\n"), 1), Status::Changed);
+        assert_eq!(check(Part::Two, &hash, 3), Status::Unrecorded);
+    }
+
+    #[test]
+    fn record_replaces_existing_entry() {
+        let mut store = store();
+        let mut entry = store.get(2022, 1, Part::One).unwrap().clone();
+        entry.answer = Answer::from("changed");
+        store.record(entry.clone());
+        assert_eq!(store.entries, vec![entry]);
+    }
+
+    #[test]
+    fn entries_round_trip_through_json() {
+        let store = store();
+        let json = serde_json::to_string(&store.entries).unwrap();
+        let entries: Vec<Entry> = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries, store.entries);
+    }
+}